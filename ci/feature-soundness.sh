@@ -18,3 +18,11 @@ cargo clippy --no-default-features --features default,ssr -- --deny=warnings
 cargo clippy --no-default-features --features csr,default,ssr -- --deny=warnings
 cargo clippy --no-default-features --features hydration,ssr -- --deny=warnings
 cargo clippy --no-default-features --features default,hydration,ssr -- --deny=warnings
+cargo clippy --no-default-features --features trace_dom -- --deny=warnings
+cargo clippy --no-default-features --features default,trace_dom -- --deny=warnings
+cargo clippy --no-default-features --features hydration,trace_dom -- --deny=warnings
+cargo clippy --no-default-features --features default,hydration,trace_dom -- --deny=warnings
+cargo clippy --no-default-features --features ssr,trace_dom -- --deny=warnings
+cargo clippy --no-default-features --features default,ssr,trace_dom -- --deny=warnings
+cargo clippy --no-default-features --features hydration,ssr,trace_dom -- --deny=warnings
+cargo clippy --no-default-features --features default,hydration,ssr,trace_dom -- --deny=warnings
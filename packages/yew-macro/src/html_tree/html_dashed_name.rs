@@ -15,7 +15,35 @@ use crate::{non_capitalized_ascii, Peek};
 #[derive(Clone, PartialEq, Eq)]
 pub struct HtmlDashedName {
     pub name: Ident,
-    pub extended: Vec<(Token![-], Ident)>,
+    pub extended: Vec<(Separator, Ident)>,
+}
+
+/// Punctuation joining the parts of a [`HtmlDashedName`].
+///
+/// Colons are only accepted by [`HtmlDashedName::parse_attribute_name`], so that namespaced
+/// attributes like `xlink:href` can be written verbatim.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Separator {
+    Dash(Token![-]),
+    Colon(Token![:]),
+}
+
+impl fmt::Display for Separator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Separator::Dash(_) => f.write_str("-"),
+            Separator::Colon(_) => f.write_str(":"),
+        }
+    }
+}
+
+impl ToTokens for Separator {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Separator::Dash(dash) => dash.to_tokens(tokens),
+            Separator::Colon(colon) => colon.to_tokens(tokens),
+        }
+    }
 }
 
 impl HtmlDashedName {
@@ -39,13 +67,42 @@ impl HtmlDashedName {
     pub fn to_lit_str(&self) -> LitStr {
         LitStr::new(&self.to_string(), self.span())
     }
+
+    /// Checks if any part of this name is joined by a colon.
+    pub fn is_namespaced(&self) -> bool {
+        self.extended
+            .iter()
+            .any(|(separator, _)| matches!(separator, Separator::Colon(_)))
+    }
+
+    /// Parses the name of an attribute, which unlike other names may also contain colons.
+    pub fn parse_attribute_name(input: ParseStream) -> syn::Result<Self> {
+        Self::parse_with_colons(input, true)
+    }
+
+    fn parse_with_colons(input: ParseStream, allow_colons: bool) -> syn::Result<Self> {
+        let name = input.call(Ident::parse_any)?;
+        let mut extended = Vec::new();
+        loop {
+            let separator = if input.peek(Token![-]) {
+                Separator::Dash(input.parse()?)
+            } else if allow_colons && input.peek(Token![:]) && !input.peek(Token![::]) {
+                Separator::Colon(input.parse()?)
+            } else {
+                break;
+            };
+            extended.push((separator, input.call(Ident::parse_any)?));
+        }
+
+        Ok(HtmlDashedName { name, extended })
+    }
 }
 
 impl fmt::Display for HtmlDashedName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.name)?;
-        for (_, ident) in &self.extended {
-            write!(f, "-{ident}")?;
+        for (separator, ident) in &self.extended {
+            write!(f, "{separator}{ident}")?;
         }
         Ok(())
     }
@@ -63,7 +120,7 @@ impl Peek<'_, Self> for HtmlDashedName {
                 if punct.as_char() == '-' {
                     let (ident, i_cursor) = p_cursor.ident()?;
                     cursor = i_cursor;
-                    extended.push((Token![-](Span::mixed_site()), ident));
+                    extended.push((Separator::Dash(Token![-](Span::mixed_site())), ident));
                     continue;
                 }
             }
@@ -76,22 +133,16 @@ impl Peek<'_, Self> for HtmlDashedName {
 
 impl Parse for HtmlDashedName {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let name = input.call(Ident::parse_any)?;
-        let mut extended = Vec::new();
-        while input.peek(Token![-]) {
-            extended.push((input.parse::<Token![-]>()?, input.call(Ident::parse_any)?));
-        }
-
-        Ok(HtmlDashedName { name, extended })
+        Self::parse_with_colons(input, false)
     }
 }
 
 impl ToTokens for HtmlDashedName {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let HtmlDashedName { name, extended } = self;
-        let dashes = extended.iter().map(|(dash, _)| quote! {#dash});
+        let separators = extended.iter().map(|(separator, _)| quote! {#separator});
         let idents = extended.iter().map(|(_, ident)| quote! {#ident});
-        let extended = quote! { #(#dashes #idents)* };
+        let extended = quote! { #(#separators #idents)* };
         tokens.extend(quote! { #name #extended });
     }
 }
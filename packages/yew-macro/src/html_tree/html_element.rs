@@ -5,7 +5,7 @@ use quote::{quote, quote_spanned, ToTokens};
 use syn::buffer::Cursor;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Block, Expr, Ident, Lit, LitStr, Token};
+use syn::{Block, Expr, ExprLit, Ident, Lit, LitStr, Token};
 
 use super::{HtmlChildrenTree, HtmlDashedName, TagTokens};
 use crate::props::{ClassesForm, ElementProps, Prop, PropDirective};
@@ -130,10 +130,7 @@ impl ToTokens for HtmlElement {
             .unwrap_or(quote! { ::std::option::Option::None });
         let checked = checked
             .as_ref()
-            .map(|attr| {
-                let value = &attr.value;
-                quote! { ::std::option::Option::Some( #value ) }
-            })
+            .map(|attr| wrap_bool_attr_value(&attr.value))
             .unwrap_or(quote! { ::std::option::Option::None });
 
         // other attributes
@@ -164,28 +161,25 @@ impl ToTokens for HtmlElement {
                     Some((
                         key.clone(),
                         match value {
-                            Expr::Lit(e) => match &e.lit {
-                                Lit::Bool(b) => Value::Static(if b.value {
-                                    quote! { #key }
-                                } else {
-                                    return None;
-                                }),
-                                _ => Value::Dynamic(quote_spanned! {value.span()=> {
-                                    ::yew::utils::__ensure_type::<::std::primitive::bool>(#value);
-                                    #key
-                                }}),
-                            },
-                            expr => Value::Dynamic(
+                            Expr::Lit(ExprLit {
+                                lit: Lit::Bool(b), ..
+                            }) => Value::Static(if b.value {
+                                quote! { #key }
+                            } else {
+                                return None;
+                            }),
+                            expr => Value::Dynamic({
+                                let value = wrap_bool_attr_value(expr);
                                 quote_spanned! {expr.span().resolved_at(Span::call_site())=>
-                                    if #expr {
+                                    if ::std::option::Option::unwrap_or(#value, false) {
                                         ::std::option::Option::Some(
                                             ::yew::virtual_dom::AttrValue::Static(#key)
                                         )
                                     } else {
                                         ::std::option::Option::None
                                     }
-                                },
-                            ),
+                                }
+                            }),
                         },
                         *directive,
                     ))
@@ -497,6 +491,17 @@ fn wrap_attr_value<T: ToTokens>(value: T) -> TokenStream {
     }
 }
 
+/// Boolean attributes go through the same conversion as other attribute values, which allows them
+/// to be given as an `Option<bool>` where `None` leaves the attribute out.
+fn wrap_bool_attr_value<T: ToTokens>(value: T) -> TokenStream {
+    quote_spanned! {value.span().resolved_at(Span::call_site())=>
+        ::yew::html::IntoPropValue::<
+            ::std::option::Option<::std::primitive::bool>
+        >
+        ::into_prop_value(#value)
+    }
+}
+
 pub struct DynamicName {
     at: Token![@],
     expr: Option<Block>,
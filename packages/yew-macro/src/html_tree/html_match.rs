@@ -0,0 +1,128 @@
+use boolinator::Boolinator;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::buffer::Cursor;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{braced, Expr, Pat, Token};
+
+use super::HtmlRootBraced;
+use crate::PeekValue;
+
+pub struct HtmlMatch {
+    match_token: Token![match],
+    expr: Box<Expr>,
+    arms: Vec<HtmlMatchArm>,
+}
+
+impl PeekValue<()> for HtmlMatch {
+    fn peek(cursor: Cursor) -> Option<()> {
+        let (ident, _) = cursor.ident()?;
+        (ident == "match").as_option()
+    }
+}
+
+impl Parse for HtmlMatch {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let match_token = input.parse()?;
+        let expr = Box::new(input.call(Expr::parse_without_eager_brace)?);
+        match &*expr {
+            Expr::Block(syn::ExprBlock { block, .. }) if block.stmts.is_empty() => {
+                return Err(syn::Error::new(
+                    expr.span(),
+                    "missing expression for `match`",
+                ))
+            }
+            _ => {}
+        }
+        if input.is_empty() {
+            return Err(syn::Error::new(
+                expr.span(),
+                "this `match` expression has a scrutinee, but no arms",
+            ));
+        }
+
+        let content;
+        braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            arms.push(content.parse()?);
+        }
+
+        Ok(HtmlMatch {
+            match_token,
+            expr,
+            arms,
+        })
+    }
+}
+
+impl ToTokens for HtmlMatch {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let HtmlMatch {
+            match_token,
+            expr,
+            arms,
+        } = self;
+        let new_tokens = quote_spanned! {match_token.span()=>
+            match #expr {
+                #(#arms)*
+            }
+        };
+
+        tokens.extend(new_tokens);
+    }
+}
+
+pub struct HtmlMatchArm {
+    pat: Pat,
+    guard: Option<(Token![if], Box<Expr>)>,
+    fat_arrow_token: Token![=>],
+    body: HtmlRootBraced,
+}
+
+impl Parse for HtmlMatchArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pat = Pat::parse_multi_with_leading_vert(input)?;
+        let guard = if input.peek(Token![if]) {
+            let if_token = input.parse()?;
+            let cond = Box::new(input.parse()?);
+            Some((if_token, cond))
+        } else {
+            None
+        };
+        let fat_arrow_token: Token![=>] = input.parse()?;
+        if HtmlRootBraced::peek(input.cursor()).is_none() {
+            return Err(syn::Error::new(
+                fat_arrow_token.span(),
+                "expected a block after `=>` (hint: wrap the html of a `match` arm in braces: `{ \
+                 ... }`)",
+            ));
+        }
+        let body = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(HtmlMatchArm {
+            pat,
+            guard,
+            fat_arrow_token,
+            body,
+        })
+    }
+}
+
+impl ToTokens for HtmlMatchArm {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let HtmlMatchArm {
+            pat,
+            guard,
+            fat_arrow_token,
+            body,
+        } = self;
+        let guard = guard
+            .as_ref()
+            .map(|(if_token, cond)| quote! { #if_token #cond });
+
+        tokens.extend(quote! { #pat #guard #fat_arrow_token #body, });
+    }
+}
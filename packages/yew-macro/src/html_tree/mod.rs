@@ -15,6 +15,7 @@ mod html_element;
 mod html_if;
 mod html_iterable;
 mod html_list;
+mod html_match;
 mod html_node;
 mod lint;
 mod tag;
@@ -26,6 +27,7 @@ use html_element::HtmlElement;
 use html_if::HtmlIf;
 use html_iterable::HtmlIterable;
 use html_list::HtmlList;
+use html_match::HtmlMatch;
 use html_node::HtmlNode;
 use tag::TagTokens;
 
@@ -35,6 +37,7 @@ pub enum HtmlType {
     List,
     Element,
     If,
+    Match,
     Empty,
 }
 
@@ -44,6 +47,7 @@ pub enum HtmlTree {
     List(Box<HtmlList>),
     Element(Box<HtmlElement>),
     If(Box<HtmlIf>),
+    Match(Box<HtmlMatch>),
     Empty,
 }
 
@@ -58,6 +62,7 @@ impl Parse for HtmlTree {
             HtmlType::Block => HtmlTree::Block(Box::new(input.parse()?)),
             HtmlType::List => HtmlTree::List(Box::new(input.parse()?)),
             HtmlType::If => HtmlTree::If(Box::new(input.parse()?)),
+            HtmlType::Match => HtmlTree::Match(Box::new(input.parse()?)),
         };
         Ok(html_tree)
     }
@@ -81,6 +86,8 @@ impl HtmlTree {
             Some(HtmlType::Block)
         } else if HtmlIf::peek(input.cursor()).is_some() {
             Some(HtmlType::If)
+        } else if HtmlMatch::peek(input.cursor()).is_some() {
+            Some(HtmlType::Match)
         } else if input.peek(Token![<]) {
             let _lt: Token![<] = input.parse().ok()?;
 
@@ -128,6 +135,7 @@ impl ToTokens for HtmlTree {
             HtmlTree::List(list) => list.to_tokens(tokens),
             HtmlTree::Block(block) => block.to_tokens(tokens),
             HtmlTree::If(block) => block.to_tokens(tokens),
+            HtmlTree::Match(block) => block.to_tokens(tokens),
         }
     }
 }
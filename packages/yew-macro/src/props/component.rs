@@ -191,7 +191,12 @@ impl TryFrom<Props> for ComponentProps {
 fn validate(props: Props) -> Result<Props, syn::Error> {
     props.check_no_duplicates()?;
     props.check_all(|prop| {
-        if !prop.label.extended.is_empty() {
+        if prop.label.is_namespaced() {
+            Err(syn::Error::new_spanned(
+                &prop.label,
+                "namespaced names like `xlink:href` are only allowed for attributes of elements",
+            ))
+        } else if !prop.label.extended.is_empty() {
             Err(syn::Error::new_spanned(
                 &prop.label,
                 "expected a valid Rust identifier",
@@ -171,6 +171,11 @@ static LISTENER_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "oncopy",
         "oncut",
         "onpaste",
+        // UI Events
+        // From: https://w3c.github.io/uievents/#events-compositionevents
+        "oncompositionend",
+        "oncompositionstart",
+        "oncompositionupdate",
         // Others
         // From: https://developer.mozilla.org/en-US/docs/Web/API/GlobalEventHandlers
         "onanimationcancel",
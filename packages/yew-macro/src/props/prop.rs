@@ -83,7 +83,7 @@ impl Prop {
         input: ParseStream,
         directive: Option<PropDirective>,
     ) -> syn::Result<Self> {
-        let label = input.parse::<HtmlDashedName>()?;
+        let label = input.call(HtmlDashedName::parse_attribute_name)?;
         let equals = input.parse::<Token![=]>().map_err(|_| {
             syn::Error::new_spanned(
                 &label,
@@ -304,6 +304,9 @@ impl Parse for PropList {
         // Stop parsing props if a base expression preceded by `..` is reached
         while !input.is_empty() && !input.peek(Token![..]) {
             props.push(input.parse()?);
+            // Props used to be separated by commas. They are optional now, but still accepted
+            // so that older code keeps compiling.
+            input.parse::<Option<Token![,]>>()?;
         }
 
         Ok(Self::new(props))
@@ -75,6 +75,10 @@ mod t3 {
         ::yew::props! { Props { b: 1 } };
         ::yew::props! { Props { a: 1, b: 2 } };
     }
+
+    fn fields_can_be_set_in_any_order() {
+        ::yew::props! { Props { b: 2, a: 1 } };
+    }
 }
 
 mod t4 {
@@ -62,4 +62,17 @@ fn main() {
             { for ::std::iter::Iterator::map(0..3, item) }
         </ul>
     };
+
+    let maybe_node = ::std::option::Option::Some(::yew::html! { <span>{ "maybe" }</span> });
+    ::yew::html! { <div>{ maybe_node }</div> };
+    let no_node = ::std::option::Option::None::<::yew::Html>;
+    ::yew::html! { <div>{ no_node }</div> };
+
+    let nodes = ::std::vec![
+        ::yew::html! { <li>{ "one" }</li> },
+        ::yew::html! { <li>{ "two" }</li> }
+    ];
+    ::yew::html! { <ul>{ nodes }</ul> };
+    let no_nodes = ::std::vec::Vec::<::yew::Html>::new();
+    ::yew::html! { <ul>{ no_nodes }</ul> };
 }
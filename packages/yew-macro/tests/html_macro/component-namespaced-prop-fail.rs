@@ -0,0 +1,20 @@
+use yew::prelude::*;
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct ChildProperties {
+    #[prop_or_default]
+    pub href: String,
+}
+
+#[function_component]
+fn Child(_props: &ChildProperties) -> Html {
+    html! {}
+}
+
+fn compile_fail() {
+    html! { <Child xlink:href="#wrench" /> };
+    html! { <Child href:lang-code="en" /> };
+    html! { <Child xml:lang="en" ..ChildProperties::default() /> };
+}
+
+fn main() {}
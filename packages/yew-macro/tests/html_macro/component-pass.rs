@@ -194,6 +194,7 @@ fn compile_pass() {
     ::yew::html! {
         <>
             <Child int=1 string="child" />
+            <Child int=1, string="child", />
             <Child int=1 />
             <Child int={1+1} />
             <Child int=1 vec={::std::vec![1]} />
@@ -34,9 +34,9 @@ fn compile_fail() {
 
     // boolean attribute type mismatch
     html! { <input checked=1 /> };
-    html! { <input checked={Some(false)} /> };
+    html! { <input checked={Some(1)} /> };
     html! { <input disabled=1 /> };
-    html! { <input disabled={Some(true)} /> };
+    html! { <input disabled={Some(1)} /> };
     html! { <option selected=1 /> };
 
     // normal attribute type mismatch
@@ -45,7 +45,7 @@ fn compile_fail() {
     html! { <a href={()} /> };
     html! { <input string={NotToString} /> };
     html! { <a media={Some(NotToString)} /> };
-    html! { <a href={Some(5)} /> };
+    html! { <a href={Some(true)} /> };
 
     // listener type mismatch
     html! { <input onclick=1 /> };
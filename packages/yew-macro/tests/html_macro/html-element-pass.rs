@@ -124,6 +124,31 @@ fn compile_pass() {
 
     let option_vnode = ::std::option::Option::Some(::yew::html! {});
     ::yew::html! { <div>{option_vnode}</div> };
+
+    // any expression can be used as an attribute value
+    let size = "large";
+    let label = <::std::string::String as ::std::convert::From<&::std::primitive::str>>::from("Entry");
+    ::yew::html! {
+        <div
+            class={::std::format!("item item--{}", size)}
+            title={&label}
+            data-label={label.to_lowercase()}
+        />
+    };
+
+    // numbers are converted to strings
+    let width = 120;
+    let count = ::std::option::Option::Some(3_usize);
+    ::yew::html! { <div width={width} data-opacity={0.5} data-count={count} /> };
+
+    // boolean attributes may be optional
+    let disabled = ::std::option::Option::Some(true);
+    let checked: ::std::option::Option<::std::primitive::bool> = ::std::option::Option::None;
+    ::yew::html! { <input {disabled} {checked} hidden={::std::option::Option::Some(false)} /> };
+
+    // props may still be separated by commas
+    ::yew::html! { <div class="header", id="title",></div> };
+    ::yew::html! { <input value="value", disabled=true, /> };
 }
 
 fn main() {}
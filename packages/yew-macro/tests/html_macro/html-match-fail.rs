@@ -0,0 +1,10 @@
+use yew::prelude::*;
+
+fn compile_fail() {
+    html! { match {} };
+    html! { match true };
+    html! { match true { _ => <div/> } };
+    html! { match true { true => {} false => {}, _ } };
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+#![no_implicit_prelude]
+
+enum Filter {
+    All,
+    Active,
+    Completed,
+}
+
+fn compile_pass_lit() {
+    ::yew::html! { match true { true => {} false => {} } };
+    ::yew::html! { match true { true => { <div/> } false => { <div/><div/> } } };
+    ::yew::html! { match true { true => { <><div/><div/></> }, false => {}, } };
+    ::yew::html! { match true { true => { { ::yew::html! {} } } _ => {} } };
+    ::yew::html! { match ::std::option::Option::Some("text") { ::std::option::Option::Some(text) => { <span>{ text }</span> } ::std::option::Option::None => {} } };
+    ::yew::html! { <><div/>match true { _ => {} }<div/></> };
+    ::yew::html! { <div>match true { _ => {} }</div> };
+}
+
+fn compile_pass_expr() {
+    let filter = Filter::All;
+    let count = 0;
+
+    ::yew::html! {
+        match filter {
+            Filter::All => { <div/> }
+            Filter::Active | Filter::Completed if count == 0 => {}
+            Filter::Active => { <span>{ count }</span> }
+            Filter::Completed => { <><div/><div/></> }
+        }
+    };
+    ::yew::html! { <ul>match count { 0 => {} n => { <li>{ n }</li> } }</ul> };
+}
+
+fn main() {}
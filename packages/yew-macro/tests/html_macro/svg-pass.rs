@@ -66,4 +66,14 @@ fn main() {
             </defs>
         </svg>
     };
+
+    // namespaced attributes
+    ::yew::html! {
+        <svg xmlns:xlink="http://www.w3.org/1999/xlink">
+            <use xlink:href="#wrench" xml:lang="en"/>
+            <foreignObject width="100" height="100">
+                <div>{"Hello"}</div>
+            </foreignObject>
+        </svg>
+    };
 }
@@ -114,6 +114,7 @@ fn root() -> Html {
 // - parameters in the path
 // - query parameters
 // - 404 redirects
+// - back and forward navigation
 #[test]
 async fn router_works() {
     yew::Renderer::<Root>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
@@ -142,4 +143,17 @@ async fn router_works() {
     assert_eq!("3", obtain_result_by_id("result-params"));
     assert_eq!("baz", obtain_result_by_id("result-query"));
     assert_eq!(initial_length + 1, history_length());
+
+    // navigating through the browser history fires `popstate` asynchronously
+    gloo::utils::window().history().unwrap().back().unwrap();
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!("2", obtain_result_by_id("result-params"));
+    assert_eq!("bar", obtain_result_by_id("result-query"));
+
+    gloo::utils::window().history().unwrap().forward().unwrap();
+
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!("3", obtain_result_by_id("result-params"));
+    assert_eq!("baz", obtain_result_by_id("result-query"));
 }
@@ -61,11 +61,17 @@ where
     }
 
     /// Schedule the app for destruction
+    ///
+    /// All components are destroyed and dropped along with their state, which stops any timers or
+    /// connections they own. Their nodes are removed from the host element and their event
+    /// listeners are detached.
     #[tracing::instrument(
         level = tracing::Level::DEBUG,
         skip_all,
     )]
     pub fn destroy(self) {
+        #[cfg(feature = "trace_dom")]
+        crate::dom_bundle::stats::remove_callback(self.scope.id);
         self.scope.destroy(false)
     }
 }
@@ -12,6 +12,12 @@ use crate::html::ImplicitClone;
 /// Universal callback wrapper.
 ///
 /// An `Rc` wrapper is used to make it cloneable.
+///
+/// Callbacks are compared by the pointer of that `Rc`: a callback only equals its clones. Two
+/// callbacks created separately are never equal, even if they were created from the same closure.
+/// A callback that is recreated on every render therefore makes the props of a child look changed
+/// on every render. Memoize it with [`use_callback`](crate::functional::use_callback) or keep it in
+/// the state of a struct component to avoid that.
 pub struct Callback<IN, OUT = ()> {
     /// A callback which can be called multiple times
     pub(crate) cb: Rc<dyn Fn(IN) -> OUT>,
@@ -125,6 +131,14 @@ mod test {
         assert_eq!(*emit([true, false], |cb| cb), vec![true, false]);
     }
 
+    #[test]
+    fn test_eq() {
+        let func = |v: u32| v + 1;
+        let cb = Callback::from(func);
+        assert_eq!(cb, cb.clone());
+        assert_ne!(cb, Callback::from(func));
+    }
+
     #[test]
     fn test_reform() {
         assert_eq!(
@@ -133,6 +147,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_reform_chain() {
+        // The function passed last runs first, like nested views mapping their messages outwards
+        assert_eq!(
+            *emit([1, 2], |cb| cb
+                .reform(|v: i32| format!("parent({})", v))
+                .reform(|v: i32| v * 10)),
+            vec!["parent(10)".to_owned(), "parent(20)".to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_reform_converts_input_and_returns_output() {
+        let len = Callback::from(|v: String| v.len());
+        let cb = len.reform(|v: u32| v.to_string());
+        assert_eq!(cb.emit(1234), 4);
+        assert_eq!(cb.emit(7), 1);
+    }
+
     #[test]
     fn test_filter_reform() {
         assert_eq!(
@@ -4,7 +4,7 @@ use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::hash::Hash;
 use std::ops::Deref;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use web_sys::Element;
 
@@ -21,6 +21,17 @@ pub(super) struct BList {
     /// All [BNode]s in the BList have keys
     fully_keyed: bool,
     key: Option<Key>,
+    /// Set if the children of the last reconciled [VList] are shared with other [VList]s
+    last_render: Option<SharedRender>,
+}
+
+/// Children that were reconciled into a [BList], by the [DomSlot] they were placed at
+#[derive(Debug)]
+struct SharedRender {
+    children: Weak<Vec<VNode>>,
+    slot: DomSlot,
+    /// The position of the first child
+    first: DomSlot,
 }
 
 impl VList {
@@ -137,6 +148,7 @@ impl BList {
             rev_children: vec![],
             fully_keyed: true,
             key: None,
+            last_render: None,
         }
     }
 
@@ -429,6 +441,22 @@ impl Reconcilable for VList {
         // The left items are known since we want to insert them
         // (self.children). For the right ones, we will look at the bundle,
         // i.e. the current DOM list element that we want to replace with self.
+        //
+        // Children shared with the previously reconciled list, e.g. by reusing the same `Html`,
+        // can only result in the same bundle, so they are skipped entirely if placed at the
+        // same slot.
+        let shared = self
+            .children
+            .as_ref()
+            .filter(|children| Rc::strong_count(children) > 1)
+            .map(Rc::downgrade);
+        if let (Some(children), Some(last)) = (&shared, &blist.last_render) {
+            if self.key == blist.key && children.ptr_eq(&last.children) && slot.is_same(&last.slot)
+            {
+                test_log!("skipping shared children: {:?}", blist.rev_children);
+                return last.first.clone();
+            }
+        }
         let (key, mut fully_keyed, mut lefts) = self.split_for_blist();
 
         if lefts.is_empty() {
@@ -446,6 +474,7 @@ impl Reconcilable for VList {
         if let Some(additional) = lefts.len().checked_sub(rights.len()) {
             rights.reserve_exact(additional);
         }
+        let shared = shared.map(|children| (children, slot.clone()));
         let first = if fully_keyed && blist.fully_keyed {
             BList::apply_keyed(root, parent_scope, parent, slot, lefts, rights)
         } else {
@@ -453,6 +482,11 @@ impl Reconcilable for VList {
         };
         blist.fully_keyed = fully_keyed;
         blist.key = key;
+        blist.last_render = shared.map(|(children, slot)| SharedRender {
+            children,
+            slot,
+            first: first.clone(),
+        });
         test_log!("result: {:?}", rights);
         first
     }
@@ -487,6 +521,7 @@ mod feat_hydration {
                 rev_children: children,
                 fully_keyed,
                 key,
+                last_render: None,
             }
         }
     }
@@ -499,8 +534,8 @@ mod layout_tests {
 
     use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
 
-    use crate::html;
     use crate::tests::layout_tests::{diff_layouts, TestLayout};
+    use crate::{html, Html};
 
     wasm_bindgen_test_configure!(run_in_browser);
 
@@ -567,6 +602,157 @@ mod layout_tests {
 
         diff_layouts(vec![layout1, layout2, layout3, layout4]);
     }
+
+    #[test]
+    fn fragment_without_wrapper_element() {
+        fn rows(cells: &[&'static str]) -> Html {
+            html! {
+                <>
+                    {for cells.iter().map(|cell| html! { <tr><td>{cell}</td></tr> })}
+                </>
+            }
+        }
+
+        let layout1 = TestLayout {
+            name: "1",
+            node: html! {
+                <table><tbody>{rows(&["a", "b"])}</tbody></table>
+            },
+            expected: "<table><tbody><tr><td>a</td></tr><tr><td>b</td></tr></tbody></table>",
+        };
+
+        let layout2 = TestLayout {
+            name: "2",
+            node: html! {
+                <table><tbody>{rows(&["a"])}<tr><td>{"c"}</td></tr></tbody></table>
+            },
+            expected: "<table><tbody><tr><td>a</td></tr><tr><td>c</td></tr></tbody></table>",
+        };
+
+        let layout3 = TestLayout {
+            name: "3",
+            node: html! {
+                <table><tbody>{rows(&[])}</tbody></table>
+            },
+            expected: "<table><tbody></tbody></table>",
+        };
+
+        diff_layouts(vec![layout1, layout2, layout3]);
+    }
+
+    #[test]
+    fn empty_branch_leaves_no_placeholder() {
+        // Positions are tracked in the bundle, so an empty branch doesn't need a node in the DOM
+        // to mark where its content goes once it is shown again.
+        fn entry(show: bool) -> Html {
+            html! {
+                <p>
+                    {"a"}
+                    if show {
+                        <b>{"b"}</b>
+                        <i>{"i"}</i>
+                    }
+                    {"c"}
+                </p>
+            }
+        }
+
+        let layout1 = TestLayout {
+            name: "1",
+            node: entry(true),
+            expected: "<p>a<b>b</b><i>i</i>c</p>",
+        };
+
+        let layout2 = TestLayout {
+            name: "2",
+            node: entry(false),
+            expected: "<p>ac</p>",
+        };
+
+        let layout3 = TestLayout {
+            name: "3",
+            node: entry(true),
+            expected: "<p>a<b>b</b><i>i</i>c</p>",
+        };
+
+        diff_layouts(vec![layout1, layout2, layout3]);
+    }
+
+    #[test]
+    fn option_and_vec_children() {
+        fn entry(maybe_node: Option<Html>, nodes: Vec<Html>) -> Html {
+            html! {
+                <p>
+                    {"a"}
+                    { maybe_node }
+                    { nodes }
+                    {"c"}
+                </p>
+            }
+        }
+
+        let layout1 = TestLayout {
+            name: "1",
+            node: entry(None, vec![]),
+            expected: "<p>ac</p>",
+        };
+
+        let layout2 = TestLayout {
+            name: "2",
+            node: entry(Some(html! { <b>{"b"}</b> }), vec![]),
+            expected: "<p>a<b>b</b>c</p>",
+        };
+
+        let layout3 = TestLayout {
+            name: "3",
+            node: entry(None, vec![html! { <i>{"1"}</i> }, html! { <i>{"2"}</i> }]),
+            expected: "<p>a<i>1</i><i>2</i>c</p>",
+        };
+
+        let layout4 = TestLayout {
+            name: "4",
+            node: entry(Some(html! { <b>{"b"}</b> }), vec![html! { <i>{"1"}</i> }]),
+            expected: "<p>a<b>b</b><i>1</i>c</p>",
+        };
+
+        diff_layouts(vec![layout1, layout2, layout3, layout4]);
+    }
+
+    #[test]
+    fn shared_children_at_other_slots() {
+        let shared = html! {
+            <>
+                <b>{"1"}</b>
+                <b>{"2"}</b>
+            </>
+        };
+
+        let layout1 = TestLayout {
+            name: "1",
+            node: html! { <p>{shared.clone()}{"x"}</p> },
+            expected: "<p><b>1</b><b>2</b>x</p>",
+        };
+
+        let layout2 = TestLayout {
+            name: "2",
+            node: html! { <p>{shared.clone()}{"y"}</p> },
+            expected: "<p><b>1</b><b>2</b>y</p>",
+        };
+
+        let layout3 = TestLayout {
+            name: "3",
+            node: html! { <p>{"z"}{shared.clone()}</p> },
+            expected: "<p>z<b>1</b><b>2</b></p>",
+        };
+
+        let layout4 = TestLayout {
+            name: "4",
+            node: html! { <p>{shared}</p> },
+            expected: "<p><b>1</b><b>2</b></p>",
+        };
+
+        diff_layouts(vec![layout1, layout2, layout3, layout4]);
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -575,11 +761,13 @@ mod layout_tests_keys {
     extern crate self as yew;
 
     use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
-    use web_sys::Node;
+    use web_sys::{Element, Node};
 
+    use crate::dom_bundle::utils::setup_parent;
+    use crate::dom_bundle::{DomSlot, Reconcilable};
     use crate::tests::layout_tests::{diff_layouts, TestLayout};
     use crate::virtual_dom::VNode;
-    use crate::{html, Children, Component, Context, Html, Properties};
+    use crate::{html, Children, Component, Context, Html, NodeRef, Properties};
 
     wasm_bindgen_test_configure!(run_in_browser);
 
@@ -1431,4 +1619,64 @@ mod layout_tests_keys {
 
         diff_layouts(layouts);
     }
+
+    #[test]
+    fn reorder_moves_keyed_nodes() {
+        let (root, scope, parent) = setup_parent();
+
+        let ref_a = NodeRef::default();
+        let ref_b = NodeRef::default();
+        let before = html! {
+            <>
+                <input key="a" ref={&ref_a} />
+                <input key="b" ref={&ref_b} />
+            </>
+        };
+        let (_, mut bundle) = before.attach(&root, &scope, &parent, DomSlot::at_end());
+        let node_a = ref_a.get().unwrap();
+        let node_b = ref_b.get().unwrap();
+
+        let after = html! {
+            <>
+                <input key="b" ref={&ref_b} />
+                <input key="a" ref={&ref_a} />
+            </>
+        };
+        after.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut bundle);
+
+        assert_eq!(ref_a.get(), Some(node_a.clone()), "node a should be kept");
+        assert_eq!(ref_b.get(), Some(node_b.clone()), "node b should be kept");
+        assert_eq!(parent.first_child(), Some(node_b));
+        assert_eq!(parent.last_child(), Some(node_a));
+    }
+
+    #[test]
+    fn for_loop_moves_entries_by_key() {
+        fn view_entry(&(id, description): &(u32, &'static str)) -> Html {
+            html! { <li key={id}>{ description }</li> }
+        }
+
+        fn view(entries: &[(u32, &'static str)]) -> Html {
+            html! { <>{ for entries.iter().map(view_entry) }</> }
+        }
+
+        let child_nodes = |parent: &Element| {
+            let nodes = parent.child_nodes();
+            (0..nodes.length())
+                .filter_map(|i| nodes.get(i))
+                .collect::<Vec<_>>()
+        };
+
+        let (root, scope, parent) = setup_parent();
+        let mut entries = vec![(1, "a"), (2, "b"), (3, "c")];
+        let (_, mut bundle) = view(&entries).attach(&root, &scope, &parent, DomSlot::at_end());
+        let before = child_nodes(&parent);
+
+        entries.rotate_left(1);
+        view(&entries).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut bundle);
+
+        assert_eq!(parent.inner_html(), "<li>b</li><li>c</li><li>a</li>");
+        let after = child_nodes(&parent);
+        assert_eq!(after, [&before[1..], &before[..1]].concat());
+    }
 }
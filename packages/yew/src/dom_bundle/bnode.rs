@@ -4,7 +4,7 @@ use std::fmt;
 
 use web_sys::{Element, Node};
 
-use super::{BComp, BList, BPortal, BRaw, BSubtree, BSuspense, BTag, BText, DomSlot};
+use super::{trace_dom, BComp, BList, BPortal, BRaw, BSubtree, BSuspense, BTag, BText, DomSlot};
 use crate::dom_bundle::{Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
 use crate::virtual_dom::{Key, VNode};
@@ -55,6 +55,7 @@ impl ReconcileTarget for BNode {
             Self::List(blist) => blist.detach(root, parent, parent_to_detach),
             Self::Ref(ref node) => {
                 // Always remove user-defined nodes to clear possible parent references of them
+                trace_dom!(nodes_removed; dom.op = "remove_node");
                 if parent.remove_child(node).is_err() {
                     tracing::warn!("Node not found to remove VRef");
                 }
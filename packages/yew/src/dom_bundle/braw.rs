@@ -1,7 +1,7 @@
 use wasm_bindgen::JsCast;
 use web_sys::{Element, Node};
 
-use super::{BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
+use super::{trace_dom, BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
 use crate::virtual_dom::VRaw;
 use crate::AttrValue;
@@ -16,6 +16,7 @@ pub struct BRaw {
 impl BRaw {
     fn create_elements(html: &str) -> Vec<Node> {
         let div = gloo::utils::document().create_element("div").unwrap();
+        trace_dom!(dom.op = "set_inner_html");
         div.set_inner_html(html);
         let children = div.child_nodes();
         let children = js_sys::Array::from(&children);
@@ -31,6 +32,7 @@ impl BRaw {
         for _ in 0..self.children_count {
             if let Some(node) = next_node {
                 next_node = node.next_sibling();
+                trace_dom!(nodes_removed; dom.op = "remove_node");
                 parent.remove_child(&node).unwrap();
             }
         }
@@ -368,6 +370,28 @@ mod tests {
         assert_eq!(new_parent.inner_html(), HTML);
     }
 
+    #[test]
+    fn braw_unchanged_html_keeps_nodes() {
+        let (root, scope, parent) = setup_parent();
+        const HTML: &str = r#"<p>paragraph</p><a href="https://yew.rs">link</a>"#;
+
+        let elem = VNode::from_html_unchecked(HTML.into());
+        let (_, mut elem) = elem.attach(&root, &scope, &parent, DomSlot::at_end());
+        let first = parent.first_child().unwrap();
+
+        let next = VNode::from_html_unchecked(HTML.into());
+        next.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_braw(&mut elem);
+        assert!(first.is_same_node(parent.first_child().as_ref()));
+
+        const UPDATED: &str = "<p>paragraph</p>";
+        let next = VNode::from_html_unchecked(UPDATED.into());
+        next.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_braw(&mut elem);
+        assert_eq!(parent.inner_html(), UPDATED);
+        assert!(!first.is_same_node(parent.first_child().as_ref()));
+    }
+
     fn assert_braw(node: &mut BNode) -> &mut BRaw {
         if let BNode::Raw(braw) = node {
             return braw;
@@ -1,14 +1,17 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 
 use indexmap::IndexMap;
-use wasm_bindgen::{intern, JsValue};
-use web_sys::{Element, HtmlInputElement as InputElement, HtmlTextAreaElement as TextAreaElement};
+use wasm_bindgen::{intern, JsCast, JsValue};
+use web_sys::{
+    Element, HtmlElement, HtmlInputElement as InputElement, HtmlTextAreaElement as TextAreaElement,
+};
 use yew::AttrValue;
 
 use super::Apply;
-use crate::dom_bundle::BSubtree;
-use crate::virtual_dom::vtag::{InputFields, Value};
+use crate::dom_bundle::{trace_dom, BSubtree};
+use crate::html::parse_declarations;
+use crate::virtual_dom::vtag::{InputFields, Value, XLINK_NAMESPACE, XML_NAMESPACE};
 use crate::virtual_dom::{ApplyAttributeAs, Attributes};
 
 impl<T: AccessValue> Apply for Value<T> {
@@ -48,6 +51,7 @@ macro_rules! impl_access_value {
 
                 #[inline]
                 fn set_value(&self, v: &str) {
+                    trace_dom!(attributes_set; dom.op = "set_value");
                     <$type>::set_value(&self, v)
                 }
             }
@@ -70,6 +74,7 @@ impl Apply for InputFields {
         // IMPORTANT! This parameter has to be set every time it's explicitly given
         // to prevent strange behaviour in the browser when the DOM changes
         if let Some(checked) = self.checked {
+            trace_dom!(attributes_set; dom.op = "set_checked");
             el.set_checked(checked);
         }
 
@@ -81,6 +86,7 @@ impl Apply for InputFields {
         // IMPORTANT! This parameter has to be set every time it's explicitly given
         // to prevent strange behaviour in the browser when the DOM changes
         if let Some(checked) = self.checked {
+            trace_dom!(attributes_set; dom.op = "set_checked");
             el.set_checked(checked);
         }
 
@@ -99,7 +105,12 @@ impl Attributes {
             match old.get(key) {
                 Some(old_value) => {
                     if value != old_value {
-                        Self::set(el, key, value.0.as_ref(), value.1);
+                        Self::update(
+                            el,
+                            key,
+                            (value.0.as_ref(), value.1),
+                            (old_value.0.as_ref(), old_value.1),
+                        );
                     }
                 }
                 None => Self::set(el, key, value.0.as_ref(), value.1),
@@ -145,11 +156,13 @@ impl Attributes {
 
         // Update existing or set new
         for (k, new) in new.iter() {
-            if match old.get(k) {
-                Some(old) => old != new,
-                None => true,
-            } {
-                Self::set(el, k, new.0, new.1);
+            match old.get(k) {
+                Some(old) => {
+                    if old != new {
+                        Self::update(el, k, *new, *old);
+                    }
+                }
+                None => Self::set(el, k, new.0, new.1),
             }
         }
 
@@ -162,10 +175,16 @@ impl Attributes {
     }
 
     fn set(el: &Element, key: &str, value: &str, apply_as: ApplyAttributeAs) {
+        trace_dom!(attributes_set; dom.op = "set_attribute");
         match apply_as {
-            ApplyAttributeAs::Attribute => el
-                .set_attribute(intern(key), value)
-                .expect("invalid attribute key"),
+            ApplyAttributeAs::Attribute => match attribute_namespace(key) {
+                Some(namespace) => el
+                    .set_attribute_ns(Some(namespace), intern(key), value)
+                    .expect("invalid attribute key"),
+                None => el
+                    .set_attribute(intern(key), value)
+                    .expect("invalid attribute key"),
+            },
             ApplyAttributeAs::Property => {
                 let key = JsValue::from_str(key);
                 let value = JsValue::from_str(value);
@@ -174,11 +193,103 @@ impl Attributes {
         }
     }
 
+    /// Update an attribute that was previously set to `old`.
+    fn update(
+        el: &Element,
+        key: &str,
+        (new, apply_as): (&str, ApplyAttributeAs),
+        (old, old_apply_as): (&str, ApplyAttributeAs),
+    ) {
+        match (key, apply_as, old_apply_as) {
+            ("class", ApplyAttributeAs::Attribute, ApplyAttributeAs::Attribute) => {
+                Self::update_class(el, new, old)
+            }
+            ("style", ApplyAttributeAs::Attribute, ApplyAttributeAs::Attribute) => {
+                Self::update_style(el, new, old)
+            }
+            _ => Self::set(el, key, new, apply_as),
+        }
+    }
+
+    /// Add and remove only the classes that changed. Rewriting the whole `class` attribute makes
+    /// the browser recalculate the styles for every class of the element.
+    fn update_class(el: &Element, new: &str, old: &str) {
+        let class_list = el.class_list();
+        let new_classes = new.split_ascii_whitespace().collect::<HashSet<_>>();
+        let old_classes = old.split_ascii_whitespace().collect::<HashSet<_>>();
+
+        for class in old.split_ascii_whitespace() {
+            if !new_classes.contains(class) {
+                trace_dom!(attributes_removed; dom.op = "remove_class");
+                class_list.remove_1(class).expect("invalid class");
+            }
+        }
+        for class in new.split_ascii_whitespace() {
+            if !old_classes.contains(class) {
+                trace_dom!(attributes_set; dom.op = "add_class");
+                class_list.add_1(class).expect("invalid class");
+            }
+        }
+    }
+
+    /// Set and remove only the declarations that changed, for the same reason as the classes
+    /// above.
+    fn update_style(el: &Element, new: &str, old: &str) {
+        let style = match el.dyn_ref::<HtmlElement>() {
+            Some(el) => el.style(),
+            None => return Self::set(el, "style", new, ApplyAttributeAs::Attribute),
+        };
+        let new_declarations = parse_declarations(new).collect::<Vec<_>>();
+        let old_declarations = parse_declarations(old).collect::<Vec<_>>();
+
+        let mut removed_any = false;
+        for (property, _) in &old_declarations {
+            if !new_declarations.iter().any(|(new, _)| new == property) {
+                trace_dom!(attributes_removed; dom.op = "remove_style_property");
+                style
+                    .remove_property(property)
+                    .expect("could not remove style property");
+                removed_any = true;
+            }
+        }
+
+        // Setting or removing a shorthand like `margin` also changes the longhands like
+        // `margin-left` declared before it, so everything after the first changed declaration is
+        // set again to end up with the same values as assigning the attribute.
+        let unchanged = if removed_any {
+            0
+        } else {
+            new_declarations
+                .iter()
+                .zip(&old_declarations)
+                .take_while(|(new, old)| new == old)
+                .count()
+        };
+        for (property, value) in &new_declarations[unchanged..] {
+            let (value, priority) = match value.strip_suffix("!important") {
+                Some(value) => (value.trim_end(), "important"),
+                None => (*value, ""),
+            };
+            trace_dom!(attributes_set; dom.op = "set_style_property");
+            style
+                .set_property_with_priority(property, value, priority)
+                .expect("could not set style property");
+        }
+    }
+
     fn remove(el: &Element, key: &str, apply_as: ApplyAttributeAs) {
+        trace_dom!(attributes_removed; dom.op = "remove_attribute");
         match apply_as {
-            ApplyAttributeAs::Attribute => el
-                .remove_attribute(intern(key))
-                .expect("could not remove attribute"),
+            ApplyAttributeAs::Attribute => match attribute_namespace(key) {
+                Some(namespace) => {
+                    let (_, local_name) = key.split_once(':').unwrap_or(("", key));
+                    el.remove_attribute_ns(Some(namespace), intern(local_name))
+                        .expect("could not remove attribute")
+                }
+                None => el
+                    .remove_attribute(intern(key))
+                    .expect("could not remove attribute"),
+            },
             ApplyAttributeAs::Property => {
                 let key = JsValue::from_str(key);
                 js_sys::Reflect::set(el.as_ref(), &key, &JsValue::UNDEFINED)
@@ -188,6 +299,16 @@ impl Attributes {
     }
 }
 
+/// Returns the namespace of a prefixed attribute such as `xlink:href`, which has to be set with
+/// `setAttributeNS` for the browser to pick it up.
+fn attribute_namespace(key: &str) -> Option<&'static str> {
+    match key.split_once(':')?.0 {
+        "xlink" => Some(XLINK_NAMESPACE),
+        "xml" => Some(XML_NAMESPACE),
+        _ => None,
+    }
+}
+
 impl Apply for Attributes {
     type Bundle = Self;
     type Element = Element;
@@ -255,7 +376,12 @@ impl Apply for Attributes {
                     match unsafe { (new_v.get_unchecked(i), old_v.get_unchecked(i)) } {
                         (Some(new), Some(old)) => {
                             if new != old {
-                                set!(new);
+                                Self::update(
+                                    el,
+                                    key!(),
+                                    (new.0.as_ref(), new.1),
+                                    (old.0.as_ref(), old.1),
+                                );
                             }
                         }
                         (Some(new), None) => set!(new),
@@ -359,6 +485,78 @@ mod tests {
         assert_eq!(element.get_attribute("class").unwrap(), "thing");
     }
 
+    #[test]
+    fn only_changed_classes_are_updated() {
+        let (element, _) = create_element();
+        element.set_class_name("a b c");
+
+        Attributes::update_class(&element, "a c d", "a b c");
+        assert_eq!(element.class_name(), "a c d");
+
+        // Classes that are in both values are left alone
+        element.set_class_name("a");
+        Attributes::update_class(&element, "a c", "a c d");
+        assert_eq!(element.class_name(), "a");
+    }
+
+    #[test]
+    fn classes_are_only_separated_by_ascii_whitespace() {
+        let (element, _) = create_element();
+        element.set_class_name("a");
+
+        Attributes::update_class(&element, "a b\u{a0}c", "a");
+        assert_eq!(element.class_list().length(), 2);
+        assert!(element.class_list().contains("b\u{a0}c"));
+    }
+
+    #[test]
+    fn only_changed_style_declarations_are_updated() {
+        let (element, _) = create_element();
+        let style = element.unchecked_ref::<HtmlElement>().style();
+        element
+            .set_attribute("style", "display: flex; color: red;")
+            .unwrap();
+
+        // Declarations before the first one that changed are left alone
+        style.set_property("display", "block").unwrap();
+        Attributes::update_style(
+            &element,
+            "display: flex; color: blue;",
+            "display: flex; color: red;",
+        );
+        assert_eq!(style.get_property_value("display").unwrap(), "block");
+        assert_eq!(style.get_property_value("color").unwrap(), "blue");
+
+        Attributes::update_style(&element, "display: flex;", "display: flex; color: blue;");
+        assert_eq!(style.get_property_value("display").unwrap(), "flex");
+        assert_eq!(style.get_property_value("color").unwrap(), "");
+    }
+
+    #[test]
+    fn style_updates_keep_shorthands_in_order() {
+        let (element, _) = create_element();
+        let style = element.unchecked_ref::<HtmlElement>().style();
+        element
+            .set_attribute("style", "margin: 0px; margin-left: 5px;")
+            .unwrap();
+
+        Attributes::update_style(
+            &element,
+            "margin: 1px; margin-left: 5px;",
+            "margin: 0px; margin-left: 5px;",
+        );
+        assert_eq!(style.get_property_value("margin-top").unwrap(), "1px");
+        assert_eq!(style.get_property_value("margin-left").unwrap(), "5px");
+
+        Attributes::update_style(
+            &element,
+            "color: red !important;",
+            "margin: 1px; margin-left: 5px;",
+        );
+        assert_eq!(style.get_property_value("margin-left").unwrap(), "");
+        assert_eq!(style.get_property_priority("color"), "important");
+    }
+
     #[test]
     async fn macro_syntax_works() {
         #[function_component]
@@ -8,7 +8,7 @@ use wasm_bindgen::JsCast;
 use web_sys::{Element, Event, EventTarget as HtmlEventTarget};
 
 use super::Apply;
-use crate::dom_bundle::{test_log, BSubtree, EventDescriptor};
+use crate::dom_bundle::{test_log, trace_dom, BSubtree, EventDescriptor};
 use crate::virtual_dom::{Listener, Listeners};
 
 #[wasm_bindgen]
@@ -122,6 +122,11 @@ pub struct Registry {
     by_id: HashMap<u32, HashMap<EventDescriptor, Vec<Rc<dyn Listener>>>>,
 }
 
+#[cfg(feature = "trace_dom")]
+fn count_listeners(by_desc: &HashMap<EventDescriptor, Vec<Rc<dyn Listener>>>) -> usize {
+    by_desc.values().map(Vec::len).sum()
+}
+
 impl Registry {
     pub fn new() -> Self {
         Self {
@@ -153,6 +158,10 @@ impl Registry {
 
     /// Register all passed listeners under ID
     fn register(&mut self, root: &BSubtree, id: u32, listeners: &[Option<Rc<dyn Listener>>]) {
+        trace_dom!(
+            listeners_attached += listeners.iter().flatten().count();
+            listeners.op = "register"
+        );
         let mut by_desc =
             HashMap::<EventDescriptor, Vec<Rc<dyn Listener>>>::with_capacity(listeners.len());
         for l in listeners.iter().filter_map(|l| l.as_ref()).cloned() {
@@ -166,6 +175,14 @@ impl Registry {
     /// Patch an already registered set of handlers
     fn patch(&mut self, root: &BSubtree, id: &u32, listeners: &[Option<Rc<dyn Listener>>]) {
         if let Some(by_desc) = self.by_id.get_mut(id) {
+            trace_dom!(
+                listeners_detached += count_listeners(by_desc);
+                listeners.op = "unregister"
+            );
+            trace_dom!(
+                listeners_attached += listeners.iter().flatten().count();
+                listeners.op = "register"
+            );
             // Keeping empty vectors is fine. Those don't do much and should happen rarely.
             for v in by_desc.values_mut() {
                 v.clear()
@@ -181,6 +198,10 @@ impl Registry {
 
     /// Unregister any existing listeners for ID
     fn unregister(&mut self, id: &u32) {
+        trace_dom!(
+            listeners_detached += self.by_id.get(id).map_or(0, count_listeners);
+            listeners.op = "unregister"
+        );
         self.by_id.remove(id);
     }
 
@@ -202,7 +223,13 @@ mod tests {
     use std::marker::PhantomData;
 
     use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
-    use web_sys::{Event, EventInit, FocusEvent, HtmlElement, MouseEvent};
+    use web_sys::{
+        AnimationEvent, AnimationEventInit, CompositionEvent, CompositionEventInit, DataTransfer,
+        DragEvent, DragEventInit, Event, EventInit, FocusEvent, FormData, HtmlElement,
+        HtmlFormElement, HtmlInputElement, InputEvent, KeyboardEvent, MouseEvent, PointerEvent,
+        PointerEventInit, SubmitEvent, Touch, TouchEvent, TouchEventInit, TouchInit,
+        TransitionEvent, TransitionEventInit, WheelEvent, WheelEventInit,
+    };
     wasm_bindgen_test_configure!(run_in_browser);
 
     use gloo::utils::document;
@@ -307,68 +334,920 @@ mod tests {
             .unwrap()
     }
 
-    fn init<M>() -> (AppHandle<Comp<M>>, NodeRef)
-    where
-        M: Mixin + Properties + Default,
-    {
-        // Remove any existing elements
-        let body = document().query_selector("#output").unwrap().unwrap();
-        while let Some(child) = body.query_selector("div#testroot").unwrap() {
-            body.remove_child(&child).unwrap();
+    fn init<M>() -> (AppHandle<Comp<M>>, NodeRef)
+    where
+        M: Mixin + Properties + Default,
+    {
+        // Remove any existing elements
+        let body = document().query_selector("#output").unwrap().unwrap();
+        while let Some(child) = body.query_selector("div#testroot").unwrap() {
+            body.remove_child(&child).unwrap();
+        }
+
+        let root = document().create_element("div").unwrap();
+        root.set_id("testroot");
+        body.append_child(&root).unwrap();
+        let props = <Comp<M> as Component>::Properties::default();
+        let el_ref = props.state_ref.clone();
+        let app = crate::Renderer::<Comp<M>>::with_root_and_props(root, props).render();
+        scheduler::start_now();
+
+        (app, el_ref)
+    }
+
+    #[test]
+    fn synchronous() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Synchronous;
+
+        impl Mixin for Synchronous {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onclick = ctx.link().callback(|_| Message::Action);
+
+                if state.stop_listening {
+                    html! {
+                        <a ref={&ctx.props().state_ref}>{state.action}</a>
+                    }
+                } else {
+                    html! {
+                        <a {onclick} ref={&ctx.props().state_ref}>
+                            {state.action}
+                        </a>
+                    }
+                }
+            }
+        }
+
+        let (link, el) = init::<Synchronous>();
+
+        assert_count(&el, 0);
+
+        click(&el);
+        assert_count(&el, 1);
+
+        click(&el);
+        assert_count(&el, 2);
+
+        link.send_message(Message::StopListening);
+        scheduler::start_now();
+
+        click(&el);
+        assert_count(&el, 2);
+    }
+
+    #[test]
+    fn multiple_listeners_on_one_element() {
+        #[derive(Default, PartialEq, Properties)]
+        struct MultipleListeners;
+
+        impl Mixin for MultipleListeners {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onclick = ctx.link().callback(|_| Message::Action);
+                let ondblclick = ctx
+                    .link()
+                    .callback(|_| Message::SetText("dblclick".to_owned()));
+
+                html! {
+                    <div>
+                        <a {onclick} {ondblclick} ref={&ctx.props().state_ref}>
+                            {state.action}
+                        </a>
+                        <p id="text">{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, el) = init::<MultipleListeners>();
+        let text = get_el_by_selector("#text");
+
+        assert_count(&el, 0);
+        click(&el);
+        assert_count(&el, 1);
+        assert_eq!(text.text_content().unwrap(), "");
+
+        el.get()
+            .unwrap()
+            .dispatch_event(
+                &MouseEvent::new_with_mouse_event_init_dict(
+                    "dblclick",
+                    web_sys::MouseEventInit::new().bubbles(true),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        scheduler::start_now();
+        assert_count(&el, 1);
+        assert_eq!(text.text_content().unwrap(), "dblclick");
+    }
+
+    #[test]
+    fn mouse_event_data() {
+        #[derive(Default, PartialEq, Properties)]
+        struct MouseData;
+
+        impl Mixin for MouseData {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let describe = |e: MouseEvent| {
+                    Message::SetText(format!(
+                        "{} {},{} {} {}",
+                        e.type_(),
+                        e.client_x(),
+                        e.client_y(),
+                        e.button(),
+                        e.ctrl_key()
+                    ))
+                };
+                let onmousedown = ctx.link().callback(describe);
+                let onmouseup = ctx.link().callback(describe);
+
+                html! {
+                    <div {onmousedown} {onmouseup}>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<MouseData>();
+        let el = state_ref.get().unwrap();
+
+        el.dispatch_event(
+            &MouseEvent::new_with_mouse_event_init_dict(
+                "mousedown",
+                web_sys::MouseEventInit::new()
+                    .bubbles(true)
+                    .client_x(12)
+                    .client_y(34)
+                    .button(2)
+                    .ctrl_key(true),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        scheduler::start_now();
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "mousedown 12,34 2 true"
+        );
+
+        el.dispatch_event(
+            &MouseEvent::new_with_mouse_event_init_dict(
+                "mouseup",
+                web_sys::MouseEventInit::new()
+                    .bubbles(true)
+                    .client_x(56)
+                    .client_y(78),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        scheduler::start_now();
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "mouseup 56,78 0 false"
+        );
+    }
+
+    #[test]
+    fn mouse_enter_and_leave() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Hover;
+
+        impl Mixin for Hover {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onmouseenter = ctx.link().callback(|_| Message::Action);
+                let onmouseleave = ctx
+                    .link()
+                    .callback(|_| Message::SetText("leave".to_owned()));
+                let onmouseover = ctx.link().callback(|_| Message::SetText("over".to_owned()));
+                let onmouseout = ctx.link().callback(|_| Message::SetText("out".to_owned()));
+
+                html! {
+                    <div id="hover" {onmouseenter} {onmouseleave} {onmouseover} {onmouseout}>
+                        <p ref={&ctx.props().state_ref}>{state.action}</p>
+                        <span id="text">{state.text.clone()}</span>
+                    </div>
+                }
+            }
+        }
+
+        let dispatch = |el: &web_sys::Element, kind: &str, bubbles: bool| {
+            el.dispatch_event(
+                &MouseEvent::new_with_mouse_event_init_dict(
+                    kind,
+                    web_sys::MouseEventInit::new().bubbles(bubbles),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            scheduler::start_now();
+        };
+
+        let (_, el) = init::<Hover>();
+        let hover = get_el_by_selector("#hover");
+        let inner = el.get().unwrap().dyn_into::<web_sys::Element>().unwrap();
+        let text = get_el_by_selector("#text");
+
+        // The sequence a browser fires when the pointer moves from outside onto the inner element
+        dispatch(&inner, "mouseover", true);
+        dispatch(&hover, "mouseenter", false);
+        dispatch(&inner, "mouseenter", false);
+        assert_count(&el, 1);
+        assert_eq!(text.text_content().unwrap(), "over");
+
+        // Moving from the inner element back onto its parent does not leave the parent
+        dispatch(&inner, "mouseout", true);
+        dispatch(&inner, "mouseleave", false);
+        assert_eq!(text.text_content().unwrap(), "out");
+
+        dispatch(&hover, "mouseleave", false);
+        assert_count(&el, 1);
+        assert_eq!(text.text_content().unwrap(), "leave");
+    }
+
+    #[test]
+    fn onscroll_reports_position() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Scroll;
+
+        impl Mixin for Scroll {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onscroll = ctx.link().callback(|e: Event| {
+                    let el = e.target_unchecked_into::<web_sys::Element>();
+                    Message::SetText(format!(
+                        "{} {} {}",
+                        el.scroll_top(),
+                        el.scroll_height(),
+                        el.client_height()
+                    ))
+                });
+
+                html! {
+                    <div>
+                        <div id="scroll" style="height: 10px; overflow: auto;" {onscroll}>
+                            <div style="height: 100px;"></div>
+                        </div>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Scroll>();
+        let scroll = get_el_by_selector("#scroll");
+
+        scroll.set_scroll_top(20);
+        scroll
+            .dispatch_event(&Event::new("scroll").unwrap())
+            .unwrap();
+        scheduler::start_now();
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "20 100 10"
+        );
+    }
+
+    #[test]
+    fn drag_and_drop() {
+        #[derive(Default, PartialEq, Properties)]
+        struct DragAndDrop;
+
+        impl Mixin for DragAndDrop {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let ondragstart = Callback::from(|e: DragEvent| {
+                    e.data_transfer()
+                        .unwrap()
+                        .set_data("text/plain", "entry-3")
+                        .unwrap();
+                });
+                let ondragover = Callback::from(|e: DragEvent| e.prevent_default());
+                let ondrop = ctx.link().callback(|e: DragEvent| {
+                    e.prevent_default();
+                    let data = e.data_transfer().unwrap().get_data("text/plain").unwrap();
+                    Message::SetText(format!("{} {},{}", data, e.client_x(), e.client_y()))
+                });
+
+                html! {
+                    <div>
+                        <li id="source" draggable="true" {ondragstart}></li>
+                        <ul id="target" {ondragover} {ondrop}></ul>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<DragAndDrop>();
+        let source = get_el_by_selector("#source");
+        let target = get_el_by_selector("#target");
+        let data_transfer = DataTransfer::new().unwrap();
+        let drag_event = |kind: &str, x: i32, y: i32| {
+            let mut init = DragEventInit::new();
+            init.bubbles(true)
+                .cancelable(true)
+                .client_x(x)
+                .client_y(y)
+                .data_transfer(Some(&data_transfer));
+            DragEvent::new_with_event_init_dict(kind, &init).unwrap()
+        };
+
+        source
+            .dispatch_event(&drag_event("dragstart", 0, 0))
+            .unwrap();
+        // Dropping is only allowed if `dragover` is cancelled
+        assert!(!target
+            .dispatch_event(&drag_event("dragover", 10, 20))
+            .unwrap());
+        target.dispatch_event(&drag_event("drop", 10, 20)).unwrap();
+        scheduler::start_now();
+
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "entry-3 10,20"
+        );
+    }
+
+    #[test]
+    fn onwheel_delta_and_prevent_default() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Wheel;
+
+        impl Mixin for Wheel {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onwheel = ctx.link().callback(|e: WheelEvent| {
+                    // Zoom instead of scrolling the page
+                    e.prevent_default();
+                    Message::SetText(format!(
+                        "{} {} {} {}",
+                        e.delta_x(),
+                        e.delta_y(),
+                        e.delta_z(),
+                        e.delta_mode()
+                    ))
+                });
+
+                html! {
+                    <div {onwheel}>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Wheel>();
+        let el = state_ref.get().unwrap();
+
+        let mut wheel_init = WheelEventInit::new();
+        wheel_init
+            .bubbles(true)
+            .cancelable(true)
+            .delta_x(1.5)
+            .delta_y(-3.0)
+            .delta_mode(WheelEvent::DOM_DELTA_LINE);
+        let event = WheelEvent::new_with_event_init_dict("wheel", &wheel_init).unwrap();
+
+        assert!(!el.dispatch_event(&event).unwrap());
+        scheduler::start_now();
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "1.5 -3 0 1"
+        );
+    }
+
+    #[test]
+    fn onpointerdown_reports_pointer() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Pointer;
+
+        impl Mixin for Pointer {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onpointerdown = ctx.link().callback(|e: PointerEvent| {
+                    Message::SetText(format!(
+                        "{} {} {} {},{} {}",
+                        e.pointer_id(),
+                        e.pointer_type(),
+                        e.pressure(),
+                        e.client_x(),
+                        e.client_y(),
+                        e.shift_key()
+                    ))
+                });
+
+                html! {
+                    <div {onpointerdown}>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Pointer>();
+        let el = state_ref.get().unwrap();
+
+        let mut pointer_init = PointerEventInit::new();
+        pointer_init
+            .bubbles(true)
+            .pointer_id(7)
+            .pointer_type("pen")
+            .pressure(0.5)
+            .client_x(10)
+            .client_y(20)
+            .shift_key(true);
+        let event = PointerEvent::new_with_event_init_dict("pointerdown", &pointer_init).unwrap();
+
+        el.dispatch_event(&event).unwrap();
+        scheduler::start_now();
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "7 pen 0.5 10,20 true"
+        );
+    }
+
+    #[test]
+    fn ontouchstart_lists_touch_points() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Touches;
+
+        impl Mixin for Touches {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let ontouchstart = ctx.link().callback(|e: TouchEvent| {
+                    // Suppress the emulated mouse events
+                    e.prevent_default();
+                    let touches = e.touches();
+                    let points = (0..touches.length())
+                        .filter_map(|i| touches.get(i))
+                        .map(|touch| {
+                            format!(
+                                "{}:{},{}",
+                                touch.identifier(),
+                                touch.client_x(),
+                                touch.client_y()
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    Message::SetText(points.join(" "))
+                });
+
+                html! {
+                    <div {ontouchstart}>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Touches>();
+        let el = state_ref.get().unwrap();
+
+        let touches = js_sys::Array::new();
+        for (identifier, x, y) in [(0, 10, 20), (1, 30, 40)] {
+            let mut touch_init = TouchInit::new(identifier, &el);
+            touch_init.client_x(x).client_y(y);
+            touches.push(&Touch::new(&touch_init).unwrap());
+        }
+        let mut touch_init = TouchEventInit::new();
+        touch_init
+            .bubbles(true)
+            .cancelable(true)
+            .touches(&touches)
+            .changed_touches(&touches);
+        let event = TouchEvent::new_with_event_init_dict("touchstart", &touch_init).unwrap();
+
+        assert!(!el.dispatch_event(&event).unwrap());
+        scheduler::start_now();
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "0:10,20 1:30,40"
+        );
+    }
+
+    #[test]
+    fn transition_and_animation_end() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Ended;
+
+        impl Mixin for Ended {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let ontransitionend = ctx.link().callback(|e: TransitionEvent| {
+                    Message::SetText(format!("{} {}", e.property_name(), e.elapsed_time()))
+                });
+                let onanimationend = ctx.link().callback(|e: AnimationEvent| {
+                    Message::SetText(format!("{} {}", e.animation_name(), e.elapsed_time()))
+                });
+
+                html! {
+                    <div {ontransitionend} {onanimationend}>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Ended>();
+        let el = state_ref.get().unwrap();
+
+        // Transitions of several properties end one event at a time
+        for (property, elapsed, expected) in
+            [("opacity", 0.5, "opacity 0.5"), ("height", 1.0, "height 1")]
+        {
+            let mut init = TransitionEventInit::new();
+            init.bubbles(true)
+                .property_name(property)
+                .elapsed_time(elapsed);
+            let event = TransitionEvent::new_with_event_init_dict("transitionend", &init).unwrap();
+            el.dispatch_event(&event).unwrap();
+            scheduler::start_now();
+            assert_eq!(state_ref.get().unwrap().text_content().unwrap(), expected);
+        }
+
+        let mut init = AnimationEventInit::new();
+        init.bubbles(true)
+            .animation_name("fade-out")
+            .elapsed_time(0.25);
+        let event = AnimationEvent::new_with_event_init_dict("animationend", &init).unwrap();
+        el.dispatch_event(&event).unwrap();
+        scheduler::start_now();
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "fade-out 0.25"
+        );
+    }
+
+    #[test]
+    fn event_target_attributes() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Target;
+
+        impl Mixin for Target {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                // A single listener serves all buttons
+                let onclick = ctx.link().callback(|e: MouseEvent| {
+                    let target = e.target_unchecked_into::<HtmlElement>();
+                    Message::SetText(target.get_attribute("data-id").unwrap_or_default())
+                });
+
+                html! {
+                    <div {onclick}>
+                        <button id="first" data-id="1">{"First"}</button>
+                        <button id="second" data-id="2">{"Second"}</button>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Target>();
+
+        get_el_by_selector("#second").click();
+        scheduler::start_now();
+        assert_eq!(state_ref.get().unwrap().text_content().unwrap(), "2");
+
+        get_el_by_selector("#first").click();
+        scheduler::start_now();
+        assert_eq!(state_ref.get().unwrap().text_content().unwrap(), "1");
+    }
+
+    #[test]
+    fn onpaste_clipboard_data() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Paste;
+
+        fn clipboard_data(e: &Event) -> DataTransfer {
+            js_sys::Reflect::get(e, &"clipboardData".into())
+                .unwrap()
+                .unchecked_into()
+        }
+
+        impl Mixin for Paste {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onpaste = ctx.link().callback(|e: Event| {
+                    // Paste the sanitized text ourselves
+                    e.prevent_default();
+                    let text = clipboard_data(&e).get_data("text/plain").unwrap();
+                    Message::SetText(text.trim().to_owned())
+                });
+
+                html! {
+                    <div>
+                        <input id="input" {onpaste} />
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Paste>();
+        let input = get_el_by_selector("#input");
+
+        // web-sys only exposes `ClipboardEvent` with unstable APIs enabled
+        let data = DataTransfer::new().unwrap();
+        data.set_data("text/plain", "  pasted  ").unwrap();
+        let init = js_sys::Object::new();
+        js_sys::Reflect::set(&init, &"bubbles".into(), &true.into()).unwrap();
+        js_sys::Reflect::set(&init, &"cancelable".into(), &true.into()).unwrap();
+        js_sys::Reflect::set(&init, &"clipboardData".into(), &data).unwrap();
+        let constructor: js_sys::Function =
+            js_sys::Reflect::get(&gloo::utils::window(), &"ClipboardEvent".into())
+                .unwrap()
+                .unchecked_into();
+        let event: Event =
+            js_sys::Reflect::construct(&constructor, &js_sys::Array::of2(&"paste".into(), &init))
+                .unwrap()
+                .unchecked_into();
+
+        assert!(!input.dispatch_event(&event).unwrap());
+        scheduler::start_now();
+        assert_eq!(state_ref.get().unwrap().text_content().unwrap(), "pasted");
+    }
+
+    #[test]
+    fn keyboard_listeners() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Keyboard;
+
+        impl Mixin for Keyboard {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let describe = |e: KeyboardEvent| {
+                    Message::SetText(format!("{} {} {}", e.type_(), e.key(), e.repeat()))
+                };
+                let onkeydown = ctx.link().callback(describe);
+                let onkeyup = ctx.link().callback(describe);
+                let onkeypress = ctx.link().callback(describe);
+
+                html! {
+                    <div>
+                        <input type="text" {onkeydown} {onkeyup} {onkeypress} />
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Keyboard>();
+        let input_el = get_el_by_selector("input");
+
+        for (kind, key, repeat) in [
+            ("keydown", "Escape", false),
+            ("keydown", "ArrowUp", true),
+            ("keyup", "Escape", false),
+            ("keypress", "a", false),
+        ] {
+            input_el
+                .dispatch_event(
+                    &KeyboardEvent::new_with_keyboard_event_init_dict(
+                        kind,
+                        web_sys::KeyboardEventInit::new()
+                            .bubbles(true)
+                            .key(key)
+                            .repeat(repeat),
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+            scheduler::start_now();
+            assert_eq!(
+                state_ref.get().unwrap().text_content().unwrap(),
+                format!("{} {} {}", kind, key, repeat)
+            );
+        }
+    }
+
+    #[test]
+    fn prevent_default() {
+        #[derive(Default, PartialEq, Properties)]
+        struct PreventDefault;
+
+        impl Mixin for PreventDefault {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onsubmit = ctx.link().callback(|e: SubmitEvent| {
+                    e.prevent_default();
+                    Message::Action
+                });
+
+                html! {
+                    <form {onsubmit}>
+                        <p ref={&ctx.props().state_ref}>{state.action}</p>
+                    </form>
+                }
+            }
+        }
+
+        let (_, el) = init::<PreventDefault>();
+        let form = get_el_by_selector("form");
+
+        assert_count(&el, 0);
+        let not_canceled = form
+            .dispatch_event(
+                &Event::new_with_event_init_dict(
+                    "submit",
+                    EventInit::new().bubbles(true).cancelable(true),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        scheduler::start_now();
+        assert!(
+            !not_canceled,
+            "the default action should have been prevented"
+        );
+        assert_count(&el, 1);
+    }
+
+    #[test]
+    fn onsubmit_fires_once_per_submission() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Submit;
+
+        impl Mixin for Submit {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onsubmit = ctx.link().callback(|e: SubmitEvent| {
+                    e.prevent_default();
+                    Message::Action
+                });
+
+                html! {
+                    <form {onsubmit}>
+                        <input type="text" name="user" />
+                        <input type="password" name="password" />
+                        <button type="submit">{"Log in"}</button>
+                        <p ref={&ctx.props().state_ref}>{state.action}</p>
+                    </form>
+                }
+            }
         }
 
-        let root = document().create_element("div").unwrap();
-        root.set_id("testroot");
-        body.append_child(&root).unwrap();
-        let props = <Comp<M> as Component>::Properties::default();
-        let el_ref = props.state_ref.clone();
-        let app = crate::Renderer::<Comp<M>>::with_root_and_props(root, props).render();
-        scheduler::start_now();
+        let (_, el) = init::<Submit>();
+        let button = get_el_by_selector("button");
 
-        (app, el_ref)
+        assert_count(&el, 0);
+        button.click();
+        scheduler::start_now();
+        assert_count(&el, 1);
+        button.click();
+        scheduler::start_now();
+        assert_count(&el, 2);
     }
 
     #[test]
-    fn synchronous() {
+    fn onsubmit_form_data() {
         #[derive(Default, PartialEq, Properties)]
-        struct Synchronous;
+        struct Form;
 
-        impl Mixin for Synchronous {
+        impl Mixin for Form {
             fn view<C>(ctx: &Context<C>, state: &State) -> Html
             where
                 C: Component<Message = Message, Properties = MixinProps<Self>>,
             {
-                let onclick = ctx.link().callback(|_| Message::Action);
+                // All named fields are read at once when the form is submitted
+                let onsubmit = ctx.link().callback(|e: SubmitEvent| {
+                    e.prevent_default();
+                    let form = e.target_unchecked_into::<HtmlFormElement>();
+                    let data = FormData::new_with_form(&form).unwrap();
+                    let tags: Vec<String> = data
+                        .get_all("tags")
+                        .iter()
+                        .filter_map(|tag| tag.as_string())
+                        .collect();
+                    Message::SetText(format!(
+                        "user={} newsletter={} terms={} plan={} tags={}",
+                        data.get("user").as_string().unwrap_or_default(),
+                        data.has("newsletter"),
+                        data.has("terms"),
+                        data.get("plan").as_string().unwrap_or_default(),
+                        tags.join(","),
+                    ))
+                });
 
-                if state.stop_listening {
-                    html! {
-                        <a ref={&ctx.props().state_ref}>{state.action}</a>
-                    }
-                } else {
-                    html! {
-                        <a {onclick} ref={&ctx.props().state_ref}>
-                            {state.action}
-                        </a>
-                    }
+                html! {
+                    <div>
+                        <form {onsubmit}>
+                            <input type="text" name="user" value="alice" />
+                            <input type="checkbox" name="newsletter" />
+                            <input type="checkbox" name="terms" checked=true />
+                            <input type="radio" name="plan" value="free" />
+                            <input type="radio" name="plan" value="pro" checked=true />
+                            <select name="tags" multiple=true>
+                                <option value="a" selected=true>{"A"}</option>
+                                <option value="b">{"B"}</option>
+                                <option value="c" selected=true>{"C"}</option>
+                            </select>
+                            <button type="submit">{"Save"}</button>
+                        </form>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
                 }
             }
         }
 
-        let (link, el) = init::<Synchronous>();
+        let (_, el) = init::<Form>();
 
-        assert_count(&el, 0);
+        get_el_by_selector("button").click();
+        scheduler::start_now();
+        assert_eq!(
+            el.get().unwrap().text_content().unwrap(),
+            "user=alice newsletter=false terms=true plan=pro tags=a,c"
+        );
+    }
 
-        click(&el);
-        assert_count(&el, 1);
+    #[test]
+    async fn onchange_file_input() {
+        #[derive(Default, PartialEq, Properties)]
+        struct FileInput;
 
-        click(&el);
-        assert_count(&el, 2);
+        impl Mixin for FileInput {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onchange = ctx.link().callback(|e: Event| {
+                    let input = e.target_unchecked_into::<HtmlInputElement>();
+                    let files = input.files().unwrap();
+                    let names: Vec<String> = (0..files.length())
+                        .filter_map(|i| files.get(i))
+                        .map(|file| format!("{}:{}", file.name(), file.size()))
+                        .collect();
+                    Message::SetText(names.join(","))
+                });
 
-        link.send_message(Message::StopListening);
-        scheduler::start_now();
+                html! {
+                    <div>
+                        <input type="file" multiple=true {onchange} />
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
 
-        click(&el);
-        assert_count(&el, 2);
+        let (_, el) = init::<FileInput>();
+        let input = get_el_by_selector("input").unchecked_into::<HtmlInputElement>();
+
+        // Files can't be picked without a user, so they are assigned through a `DataTransfer`
+        let transfer = DataTransfer::new().unwrap();
+        for (name, content) in [("notes.txt", "hello"), ("empty.txt", "")] {
+            let file = gloo::file::File::new(name, content);
+            transfer.items().add_with_file(file.as_ref()).unwrap();
+        }
+        input.set_files(transfer.files().as_ref());
+        input
+            .dispatch_event(
+                &Event::new_with_event_init_dict("change", EventInit::new().bubbles(true)).unwrap(),
+            )
+            .unwrap();
+        scheduler::start_now();
+        assert_eq!(
+            el.get().unwrap().text_content().unwrap(),
+            "notes.txt:5,empty.txt:0"
+        );
+
+        let file = gloo::file::File::from(input.files().unwrap().get(0).unwrap());
+        let bytes = gloo::file::futures::read_as_bytes(&file).await.unwrap();
+        assert_eq!(bytes, b"hello");
     }
 
     #[test]
@@ -417,6 +1296,40 @@ mod tests {
         assert_count(&el, 1);
     }
 
+    #[test]
+    fn onerror_on_image() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Image;
+
+        impl Mixin for Image {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onerror = ctx.link().callback(|e: Event| {
+                    let image = e.target_unchecked_into::<web_sys::Element>();
+                    Message::SetText(format!("{} failed", image.get_attribute("alt").unwrap()))
+                });
+
+                html! {
+                    <div>
+                        <img alt="avatar" {onerror} />
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, el) = init::<Image>();
+
+        // Like a real `error` event of an image, this one doesn't bubble
+        get_el_by_selector("img")
+            .dispatch_event(&Event::new("error").unwrap())
+            .unwrap();
+        scheduler::start_now();
+        assert_eq!(el.get().unwrap().text_content().unwrap(), "avatar failed");
+    }
+
     #[test]
     fn bubbling() {
         #[derive(Default, PartialEq, Properties)]
@@ -575,6 +1488,46 @@ mod tests {
         assert_count(&el, 1);
     }
 
+    #[test]
+    fn onblur_on_focus_change() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Blur;
+
+        impl Mixin for Blur {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onblur = ctx.link().callback(|e: FocusEvent| {
+                    let related = e
+                        .related_target()
+                        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+                        .map(|el| el.id())
+                        .unwrap_or_default();
+                    Message::SetText(related)
+                });
+
+                html! {
+                    <div>
+                        <input id="first" type="text" {onblur} />
+                        <input id="second" type="text" />
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Blur>();
+
+        get_el_by_selector("#first").focus().unwrap();
+        scheduler::start_now();
+        assert_eq!(state_ref.get().unwrap().text_content().unwrap(), "");
+
+        get_el_by_selector("#second").focus().unwrap();
+        scheduler::start_now();
+        assert_eq!(state_ref.get().unwrap().text_content().unwrap(), "second");
+    }
+
     /// Here an event is being delivered to a DOM node which is contained
     /// in a portal. It should bubble through the portal and reach the containing
     /// element.
@@ -739,6 +1692,199 @@ mod tests {
         })
     }
 
+    #[test]
+    fn oninput_contenteditable() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Editable;
+
+        impl Mixin for Editable {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                // Editable elements have no value, their text is the content
+                let oninput = ctx.link().callback(|e: InputEvent| {
+                    let editor = e.target_unchecked_into::<HtmlElement>();
+                    Message::SetText(editor.text_content().unwrap_or_default())
+                });
+
+                html! {
+                    <div>
+                        <div id="editor" contenteditable="true" {oninput}></div>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Editable>();
+        let editor = get_el_by_selector("#editor");
+
+        editor.set_text_content(Some("Hello world"));
+        editor
+            .dispatch_event(
+                &InputEvent::new_with_event_init_dict(
+                    "input",
+                    web_sys::InputEventInit::new().bubbles(true),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        scheduler::start_now();
+        assert_eq!(
+            state_ref.get().unwrap().text_content().unwrap(),
+            "Hello world"
+        );
+    }
+
+    #[test]
+    fn oninput_restores_caret_after_rewrite() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Phone;
+
+        // Groups the digits by three, returning the value and the new position of the caret
+        fn format_phone(value: &str, caret: usize) -> (String, u32) {
+            let digits_before = value[..caret].chars().filter(char::is_ascii_digit).count();
+            let mut formatted = String::new();
+            let mut new_caret = 0;
+            for (i, digit) in value.chars().filter(char::is_ascii_digit).enumerate() {
+                if i > 0 && i % 3 == 0 {
+                    formatted.push('-');
+                }
+                formatted.push(digit);
+                if i + 1 == digits_before {
+                    new_caret = formatted.len() as u32;
+                }
+            }
+            (formatted, new_caret)
+        }
+
+        impl Mixin for Phone {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let oninput = ctx.link().callback(|e: InputEvent| {
+                    let input = e.target_unchecked_into::<HtmlInputElement>();
+                    let caret = input.selection_start().unwrap().unwrap_or_default();
+                    let (formatted, caret) = format_phone(&input.value(), caret as usize);
+                    input.set_value(&formatted);
+                    input.set_selection_range(caret, caret).unwrap();
+                    Message::SetText(formatted)
+                });
+
+                html! {
+                    <div>
+                        <input id="input" {oninput} />
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Phone>();
+        let input = get_el_by_selector("#input")
+            .dyn_into::<HtmlInputElement>()
+            .unwrap();
+        let type_value = |value: &str, caret: u32| {
+            input.set_value(value);
+            input.set_selection_range(caret, caret).unwrap();
+            input
+                .dispatch_event(
+                    &InputEvent::new_with_event_init_dict(
+                        "input",
+                        web_sys::InputEventInit::new().bubbles(true),
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+            scheduler::start_now();
+        };
+
+        type_value("1234", 4);
+        assert_eq!(input.value(), "123-4");
+        assert_eq!(input.selection_start().unwrap(), Some(5));
+
+        // Inserting in the middle keeps the caret behind the inserted digit
+        type_value("1923-4", 2);
+        assert_eq!(input.value(), "192-34");
+        assert_eq!(input.selection_start().unwrap(), Some(2));
+        assert_eq!(input.selection_end().unwrap(), Some(2));
+        assert_eq!(state_ref.get().unwrap().text_content().unwrap(), "192-34");
+    }
+
+    #[test]
+    fn composition_listeners() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Composition;
+
+        impl Mixin for Composition {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                // Intermediate input is ignored while composing
+                let oninput = ctx.link().batch_callback(|e: InputEvent| {
+                    if e.is_composing() {
+                        None
+                    } else {
+                        Some(Message::Action)
+                    }
+                });
+                let oncompositionstart = ctx
+                    .link()
+                    .callback(|_| Message::SetText("composing".to_owned()));
+                let oncompositionend = ctx.link().callback(|e: CompositionEvent| {
+                    Message::SetText(format!("committed {}", e.data().unwrap_or_default()))
+                });
+
+                html! {
+                    <div>
+                        <input id="input" {oninput} {oncompositionstart} {oncompositionend} />
+                        <p ref={&ctx.props().state_ref}>{state.action}</p>
+                        <span id="text">{state.text.clone()}</span>
+                    </div>
+                }
+            }
+        }
+
+        let (_, el) = init::<Composition>();
+        let input = get_el_by_selector("#input");
+        let text = get_el_by_selector("#text");
+        let input_event = |is_composing: bool| {
+            web_sys::InputEvent::new_with_event_init_dict(
+                "input",
+                web_sys::InputEventInit::new()
+                    .bubbles(true)
+                    .is_composing(is_composing),
+            )
+            .unwrap()
+        };
+        let composition_event = |kind: &str, data: &str| {
+            CompositionEvent::new_with_event_init_dict(
+                kind,
+                CompositionEventInit::new().bubbles(true).data(data),
+            )
+            .unwrap()
+        };
+
+        input
+            .dispatch_event(&composition_event("compositionstart", ""))
+            .unwrap();
+        input.dispatch_event(&input_event(true)).unwrap();
+        scheduler::start_now();
+        assert_count(&el, 0);
+        assert_eq!(text.text_content().unwrap(), "composing");
+
+        input
+            .dispatch_event(&composition_event("compositionend", "日本"))
+            .unwrap();
+        input.dispatch_event(&input_event(false)).unwrap();
+        scheduler::start_now();
+        assert_count(&el, 1);
+        assert_eq!(text.text_content().unwrap(), "committed 日本");
+    }
+
     #[test]
     fn onchange() {
         test_input_listener(|| {
@@ -750,6 +1896,121 @@ mod tests {
         })
     }
 
+    #[test]
+    fn onchange_select() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Select;
+
+        impl Mixin for Select {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let onchange = ctx.link().callback(|e: web_sys::Event| {
+                    let el: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                    Message::SetText(format!("{}:{}", el.selected_index(), el.value()))
+                });
+
+                html! {
+                    <div>
+                        <select {onchange}>
+                            <option value="a">{"A"}</option>
+                            <option value="b">{"B"}</option>
+                            <option value="c">{"C"}</option>
+                        </select>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Select>();
+        let select_el = get_el_by_selector("select")
+            .dyn_into::<web_sys::HtmlSelectElement>()
+            .unwrap();
+
+        assert_eq!(&state_ref.get().unwrap().text_content().unwrap(), "");
+        for (index, value) in [(2, "c"), (1, "b")] {
+            select_el.set_selected_index(index);
+            select_el
+                .dispatch_event(
+                    &web_sys::Event::new_with_event_init_dict(
+                        "change",
+                        web_sys::EventInit::new().bubbles(true),
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+            scheduler::start_now();
+            assert_eq!(
+                state_ref.get().unwrap().text_content().unwrap(),
+                format!("{}:{}", index, value)
+            );
+        }
+    }
+
+    #[test]
+    fn onchange_select_reports_value_of_grouped_option() {
+        #[derive(Default, PartialEq, Properties)]
+        struct Grouped;
+
+        impl Mixin for Grouped {
+            fn view<C>(ctx: &Context<C>, state: &State) -> Html
+            where
+                C: Component<Message = Message, Properties = MixinProps<Self>>,
+            {
+                let groups = [
+                    ("Fruit", [(1, "Apple"), (2, "Banana")]),
+                    ("Vegetables", [(3, "Carrot"), (4, "Leek")]),
+                ];
+                let onchange = ctx.link().callback(|e: web_sys::Event| {
+                    let el: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                    Message::SetText(el.value())
+                });
+
+                html! {
+                    <div>
+                        <select {onchange}>
+                            { for groups.into_iter().map(|(label, options)| html! {
+                                <optgroup {label}>
+                                    { for options.into_iter().map(|(id, name)| html! {
+                                        <option value={id}>{ name }</option>
+                                    }) }
+                                </optgroup>
+                            }) }
+                        </select>
+                        <p ref={&ctx.props().state_ref}>{state.text.clone()}</p>
+                    </div>
+                }
+            }
+        }
+
+        let (_, state_ref) = init::<Grouped>();
+        let select_el = get_el_by_selector("select")
+            .dyn_into::<web_sys::HtmlSelectElement>()
+            .unwrap();
+        assert_eq!(
+            select_el.inner_html(),
+            "<optgroup label=\"Fruit\"><option value=\"1\">Apple</option><option \
+             value=\"2\">Banana</option></optgroup><optgroup label=\"Vegetables\"><option \
+             value=\"3\">Carrot</option><option value=\"4\">Leek</option></optgroup>"
+        );
+
+        // Options are indexed across groups
+        select_el.set_selected_index(2);
+        select_el
+            .dispatch_event(
+                &web_sys::Event::new_with_event_init_dict(
+                    "change",
+                    web_sys::EventInit::new().bubbles(true),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        scheduler::start_now();
+        assert_eq!(state_ref.get().unwrap().text_content().unwrap(), "3");
+    }
+
     #[test]
     fn reentrant_listener() {
         #[derive(PartialEq, Properties, Default)]
@@ -15,7 +15,7 @@ pub use listeners::Registry;
 use wasm_bindgen::JsCast;
 use web_sys::{Element, HtmlTextAreaElement as TextAreaElement};
 
-use super::{BList, BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
+use super::{trace_dom, BList, BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
 use crate::virtual_dom::vtag::{InputFields, VTagInner, Value, MATHML_NAMESPACE, SVG_NAMESPACE};
 use crate::virtual_dom::{Attributes, Key, VTag};
@@ -82,6 +82,7 @@ impl ReconcileTarget for BTag {
             child_bundle.detach(root, &node, true);
         }
         if !parent_to_detach {
+            trace_dom!(nodes_removed; dom.op = "remove_node");
             let result = parent.remove_child(&node);
 
             if result.is_err() {
@@ -112,6 +113,7 @@ impl Reconcilable for VTag {
         parent: &Element,
         slot: DomSlot,
     ) -> (DomSlot, Self::Bundle) {
+        trace_dom!(nodes_created; dom.op = "create_element");
         let el = self.create_element(parent);
         let Self {
             listeners,
@@ -235,10 +237,12 @@ impl VTag {
     fn create_element(&self, parent: &Element) -> Element {
         let tag = self.tag();
 
+        // Children of `foreignObject` are regular html again.
         if tag == "svg"
             || parent
                 .namespace_uri()
                 .map_or(false, |ns| ns == SVG_NAMESPACE)
+                && parent.local_name() != "foreignObject"
         {
             let namespace = Some(SVG_NAMESPACE);
             document()
@@ -414,9 +418,9 @@ mod tests {
     use super::*;
     use crate::dom_bundle::utils::setup_parent;
     use crate::dom_bundle::{BNode, Reconcilable, ReconcileTarget};
-    use crate::virtual_dom::vtag::{HTML_NAMESPACE, SVG_NAMESPACE};
+    use crate::virtual_dom::vtag::{HTML_NAMESPACE, SVG_NAMESPACE, XLINK_NAMESPACE};
     use crate::virtual_dom::{AttrValue, VNode, VTag};
-    use crate::{html, Html, NodeRef};
+    use crate::{classes, html, Html, NodeRef};
 
     wasm_bindgen_test_configure!(run_in_browser);
 
@@ -635,6 +639,45 @@ mod tests {
         assert_namespace(mfrac_tag, MATHML_NAMESPACE);
     }
 
+    #[test]
+    fn supports_foreign_object() {
+        let (root, scope, parent) = setup_parent();
+        let svg_node = html! {
+            <svg>
+                <foreignObject>
+                    <div></div>
+                </foreignObject>
+            </svg>
+        };
+
+        let svg_tag = assert_vtag(svg_node);
+        let (_, svg_tag) = svg_tag.attach(&root, &scope, &parent, DomSlot::at_end());
+        let foreign_object_tag = assert_btag_ref(svg_tag.children().get(0).unwrap());
+        assert_namespace(foreign_object_tag, SVG_NAMESPACE);
+        let div_tag = assert_btag_ref(foreign_object_tag.children().get(0).unwrap());
+        assert_namespace(div_tag, HTML_NAMESPACE);
+    }
+
+    #[test]
+    fn supports_namespaced_attributes() {
+        let (root, scope, parent) = setup_parent();
+        let use_href = |elem: &BNode| {
+            assert_btag_ref(elem)
+                .reference()
+                .first_element_child()
+                .unwrap()
+                .get_attribute_ns(Some(XLINK_NAMESPACE), "href")
+        };
+
+        let elem = html! { <svg><use xlink:href="#circle" /></svg> };
+        let (_, mut elem) = elem.attach(&root, &scope, &parent, DomSlot::at_end());
+        assert_eq!(use_href(&elem), Some("#circle".to_owned()));
+
+        let next_elem = html! { <svg><use /></svg> };
+        next_elem.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(use_href(&elem), None);
+    }
+
     #[test]
     fn it_compares_values() {
         let a = html! {
@@ -824,6 +867,182 @@ mod tests {
         parent.remove();
     }
 
+    #[test]
+    fn default_value_is_not_reasserted() {
+        let (root, scope, parent) = setup_parent();
+        let draft = |initial: &'static str| html! { <input ~defaultValue={initial} /> };
+        let input = |elem: &BNode| {
+            assert_btag_ref(elem)
+                .reference()
+                .dyn_ref::<InputElement>()
+                .unwrap()
+                .clone()
+        };
+
+        let (_, mut elem) = draft("Draft").attach(&root, &scope, &parent, DomSlot::at_end());
+        assert_eq!(input(&elem).value(), "Draft");
+
+        // User input
+        input(&elem).set_value("User input");
+        draft("Draft").reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(input(&elem).value(), "User input");
+
+        // A new default doesn't replace what the user typed either
+        draft("Other").reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(input(&elem).default_value(), "Other");
+        assert_eq!(input(&elem).value(), "User input");
+
+        parent.remove();
+    }
+
+    #[test]
+    fn controlled_input_keeps_caret_position() {
+        let (root, scope, parent) = setup_parent();
+
+        let elem = html! { <input value="Hello" /> };
+        let (_, mut elem) = elem.attach(&root, &scope, &parent, DomSlot::at_end());
+        let input = assert_btag_ref(&elem)
+            .reference()
+            .clone()
+            .dyn_into::<InputElement>()
+            .unwrap();
+
+        // The user types a character in the middle of the text, which the model echoes back
+        input.set_value("Helllo");
+        input.set_selection_range(4, 4).unwrap();
+
+        let next_elem = html! { <input value="Helllo" /> };
+        next_elem.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+
+        assert_eq!(input.value(), "Helllo");
+        assert_eq!(input.selection_start().unwrap(), Some(4));
+        assert_eq!(input.selection_end().unwrap(), Some(4));
+
+        // A value that differs from the model is still overwritten
+        input.set_value("Hellllo");
+        let next_elem = html! { <input value="Helllo" /> };
+        next_elem.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(input.value(), "Helllo");
+
+        parent.remove();
+    }
+
+    #[test]
+    fn focused_input_survives_sibling_toggle() {
+        let (root, scope, parent) = setup_parent();
+        let form = |signup: bool| {
+            html! {
+                <form>
+                    if signup {
+                        <label>{"Name"}</label>
+                        <input id="name" />
+                    }
+                    <input id="email" type="email" />
+                </form>
+            }
+        };
+        let is_focused = |input: &InputElement| {
+            let active = gloo::utils::document().active_element();
+            input.is_same_node(active.as_deref())
+        };
+
+        let (_, mut elem) = form(false).attach(&root, &scope, &parent, DomSlot::at_end());
+        let input = parent
+            .query_selector("#email")
+            .unwrap()
+            .unwrap()
+            .dyn_into::<InputElement>()
+            .unwrap();
+        input.focus().unwrap();
+        input.set_value("yew@example.com");
+        input.set_selection_range(3, 3).unwrap();
+
+        for signup in [true, false] {
+            form(signup).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+            assert!(input.is_connected());
+            assert!(is_focused(&input));
+            assert_eq!(input.selection_start().unwrap(), Some(3));
+        }
+
+        parent.remove();
+    }
+
+    #[test]
+    fn textarea_value_is_set_and_cleared() {
+        let (root, scope, parent) = setup_parent();
+        let textarea = |value: Option<&'static str>| html! { <textarea {value} /> };
+        let textarea_el = |elem: &BNode| {
+            assert_btag_ref(elem)
+                .reference()
+                .clone()
+                .dyn_into::<web_sys::HtmlTextAreaElement>()
+                .unwrap()
+        };
+
+        let (_, mut elem) =
+            textarea(Some("Hello")).attach(&root, &scope, &parent, DomSlot::at_end());
+        assert_eq!(textarea_el(&elem).value(), "Hello");
+
+        textarea(Some("Hello\nWorld")).reconcile_node(
+            &root,
+            &scope,
+            &parent,
+            DomSlot::at_end(),
+            &mut elem,
+        );
+        assert_eq!(textarea_el(&elem).value(), "Hello\nWorld");
+
+        textarea(None).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(textarea_el(&elem).value(), "");
+
+        parent.remove();
+    }
+
+    #[test]
+    fn controlled_select_reselects_option() {
+        let (root, scope, parent) = setup_parent();
+        let select = |selected: &[&str], multiple: bool| {
+            html! {
+                <select {multiple}>
+                    { for ["a", "b", "c"].into_iter().map(|value| html! {
+                        <option {value} ~selected={selected.contains(&value)}>{value}</option>
+                    }) }
+                </select>
+            }
+        };
+        let select_el = |elem: &BNode| {
+            assert_btag_ref(elem)
+                .reference()
+                .clone()
+                .dyn_into::<web_sys::HtmlSelectElement>()
+                .unwrap()
+        };
+
+        let (_, mut elem) = select(&["b"], false).attach(&root, &scope, &parent, DomSlot::at_end());
+        assert_eq!(select_el(&elem).value(), "b");
+
+        // The user picks another option, which makes the selection dirty
+        select_el(&elem).set_selected_index(2);
+
+        select(&["a"], false).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(select_el(&elem).value(), "a");
+
+        select(&["a", "c"], true).reconcile_node(
+            &root,
+            &scope,
+            &parent,
+            DomSlot::at_end(),
+            &mut elem,
+        );
+        let selected = select_el(&elem).selected_options();
+        let selected = (0..selected.length())
+            .map(|i| selected.item(i).unwrap().text_content().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(selected, ["a", "c"]);
+
+        parent.remove();
+    }
+
     #[test]
     fn dynamic_tags_work() {
         let (root, scope, parent) = setup_parent();
@@ -898,6 +1117,27 @@ mod tests {
         assert!(node_ref.get().is_none());
     }
 
+    #[test]
+    fn removed_child_resets_node_ref() {
+        let (root, scope, parent) = setup_parent();
+
+        let node_ref = NodeRef::default();
+        let before = html! {
+            <div>
+                <input ref={&node_ref} />
+            </div>
+        };
+        let (_, mut elem) = before.attach(&root, &scope, &parent, DomSlot::at_end());
+        assert!(node_ref.cast::<InputElement>().is_some());
+
+        let after = html! { <div></div> };
+        after.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert!(
+            node_ref.get().is_none(),
+            "node_ref should not point at a removed element"
+        );
+    }
+
     #[test]
     fn vtag_reuse_should_reset_ancestors_node_ref() {
         let (root, scope, parent) = setup_parent();
@@ -996,6 +1236,251 @@ mod tests {
             "<div tabindex=\"0\"></div>"
         );
     }
+
+    #[test]
+    fn boolean_attributes_are_added_and_removed() {
+        let (root, scope, parent) = setup_parent();
+        let button = |busy: bool| html! { <button disabled={busy} hidden={!busy}></button> };
+        let button_el = |elem: &BNode| {
+            assert_btag_ref(elem)
+                .reference()
+                .clone()
+                .dyn_into::<web_sys::HtmlButtonElement>()
+                .unwrap()
+        };
+
+        let (_, mut elem) = button(true).attach(&root, &scope, &parent, DomSlot::at_end());
+        let el = button_el(&elem);
+        assert!(el.disabled());
+        assert!(el.has_attribute("disabled"));
+        assert!(!el.has_attribute("hidden"));
+
+        button(false).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        let el = button_el(&elem);
+        assert!(!el.disabled());
+        assert!(!el.has_attribute("disabled"));
+        assert!(el.has_attribute("hidden"));
+
+        button(true).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert!(button_el(&elem).disabled());
+    }
+
+    #[test]
+    fn data_and_aria_attributes_are_diffed() {
+        let (root, scope, parent) = setup_parent();
+        let outer_html = |elem: &BNode| assert_btag_ref(elem).reference().outer_html();
+
+        let elem = html! { <li data-id="1" aria-hidden="true"></li> };
+        let (_, mut elem) = elem.attach(&root, &scope, &parent, DomSlot::at_end());
+        assert_eq!(
+            outer_html(&elem),
+            r#"<li data-id="1" aria-hidden="true"></li>"#
+        );
+
+        let label: Option<&str> = None;
+        let next_elem = html! { <li data-id="2" aria-label={label}></li> };
+        next_elem.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(outer_html(&elem), r#"<li data-id="2"></li>"#);
+
+        let label = Some("Entry");
+        let next_elem = html! { <li data-id="2" aria-label={label}></li> };
+        next_elem.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(
+            outer_html(&elem),
+            r#"<li data-id="2" aria-label="Entry"></li>"#
+        );
+    }
+
+    #[test]
+    fn optional_attributes_are_added_and_removed() {
+        let (root, scope, parent) = setup_parent();
+        let entry = |note: Option<&'static str>| html! { <li title={note}>{"Entry"}</li> };
+        let title = |elem: &BNode| assert_btag_ref(elem).reference().get_attribute("title");
+
+        let (_, mut elem) = entry(Some("Note")).attach(&root, &scope, &parent, DomSlot::at_end());
+        assert_eq!(title(&elem), Some("Note".to_owned()));
+
+        entry(None).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(title(&elem), None);
+
+        entry(Some("Other")).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(title(&elem), Some("Other".to_owned()));
+    }
+
+    #[test]
+    fn stale_attributes_are_removed() {
+        let (root, scope, parent) = setup_parent();
+        let outer_html = |elem: &BNode| assert_btag_ref(elem).reference().outer_html();
+
+        let elem = html! { <p title="Note" lang="en" dir="ltr"></p> };
+        let (_, mut elem) = elem.attach(&root, &scope, &parent, DomSlot::at_end());
+        assert_eq!(
+            outer_html(&elem),
+            r#"<p title="Note" lang="en" dir="ltr"></p>"#
+        );
+
+        let next_elem = html! { <p lang="de"></p> };
+        next_elem.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(outer_html(&elem), r#"<p lang="de"></p>"#);
+
+        let next_elem = html! { <p></p> };
+        next_elem.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(outer_html(&elem), "<p></p>");
+
+        let next_elem = html! { <p title="Other"></p> };
+        next_elem.reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(outer_html(&elem), r#"<p title="Other"></p>"#);
+    }
+
+    #[test]
+    fn only_changed_classes_are_updated() {
+        let (root, scope, parent) = setup_parent();
+        let entry = |completed: bool| {
+            let completed = if completed { Some("completed") } else { None };
+            html! { <li class={classes!("entry", "todo", "shadow", completed)}></li> }
+        };
+        let class_name = |elem: &BNode| assert_btag_ref(elem).reference().class_name();
+
+        let (_, mut elem) = entry(false).attach(&root, &scope, &parent, DomSlot::at_end());
+        assert_eq!(class_name(&elem), "entry todo shadow");
+
+        // Classes that didn't change are left alone, so this change is kept
+        assert_btag_ref(&elem)
+            .reference()
+            .set_class_name("entry todo");
+        entry(false).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(class_name(&elem), "entry todo");
+
+        entry(true).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(class_name(&elem), "entry todo completed");
+
+        entry(false).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        assert_eq!(class_name(&elem), "entry todo");
+    }
+
+    #[test]
+    fn changed_text_is_patched_in_place() {
+        let (root, scope, parent) = setup_parent();
+        let count = |left: usize| {
+            html! {
+                <span class="todo-count">
+                    <strong>{ left }</strong>
+                    { " item(s) left" }
+                </span>
+            }
+        };
+
+        let (_, mut elem) = count(2).attach(&root, &scope, &parent, DomSlot::at_end());
+        let strong = parent.query_selector("strong").unwrap().unwrap();
+        let text = strong.first_child().unwrap();
+        assert_eq!(text.node_value().as_deref(), Some("2"));
+
+        count(1).reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+        // Both the element and its text node are kept, only the value of the text changes
+        assert!(strong.is_connected());
+        assert!(text.is_same_node(strong.first_child().as_ref()));
+        assert_eq!(text.node_value().as_deref(), Some("1"));
+        assert_eq!(
+            assert_btag_ref(&elem).reference().outer_html(),
+            r#"<span class="todo-count"><strong>1</strong> item(s) left</span>"#
+        );
+
+        parent.remove();
+    }
+
+    #[cfg(feature = "trace_dom")]
+    #[test]
+    fn dom_operations_are_traced() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+        use web_sys::MouseEvent;
+
+        use crate::Callback;
+
+        /// Collects the `dom.op` of every event
+        #[derive(Clone, Default)]
+        struct DomOps(Arc<Mutex<Vec<String>>>);
+
+        impl DomOps {
+            fn take(&self) -> Vec<String> {
+                std::mem::take(&mut *self.0.lock().unwrap())
+            }
+        }
+
+        impl Visit for DomOps {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                if field.name() == "dom.op" {
+                    self.0.lock().unwrap().push(value.to_owned());
+                }
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+        }
+
+        impl Subscriber for DomOps {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _span: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                event.record(&mut self.clone());
+            }
+
+            fn enter(&self, _span: &Id) {}
+
+            fn exit(&self, _span: &Id) {}
+        }
+
+        let (root, scope, parent) = setup_parent();
+        let entry = |title: Option<&'static str>, text: &'static str| {
+            let onclick = Callback::from(|_: MouseEvent| ());
+            html! { <li {title} {onclick}>{ text }</li> }
+        };
+
+        let ops = DomOps::default();
+        tracing::subscriber::with_default(ops.clone(), || {
+            let (_, mut elem) =
+                entry(Some("Note"), "a").attach(&root, &scope, &parent, DomSlot::at_end());
+            assert_eq!(
+                ops.take(),
+                [
+                    "create_element",
+                    "insert_node",
+                    "set_attribute",
+                    "add_event_listener",
+                    "create_text",
+                    "insert_node"
+                ]
+            );
+
+            entry(Some("Note"), "b").reconcile_node(
+                &root,
+                &scope,
+                &parent,
+                DomSlot::at_end(),
+                &mut elem,
+            );
+            assert_eq!(ops.take(), ["update_text"]);
+
+            entry(None, "b").reconcile_node(&root, &scope, &parent, DomSlot::at_end(), &mut elem);
+            assert_eq!(ops.take(), ["remove_attribute"]);
+
+            // Children are removed together with their parent
+            elem.detach(&root, &parent, false);
+            assert_eq!(ops.take(), ["remove_node"]);
+        });
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -1181,6 +1666,47 @@ mod tests_without_browser {
         );
     }
 
+    #[test]
+    fn html_if_else_if() {
+        let view = |n: u32| {
+            html! {
+                <div>
+                    if n == 0 {
+                        <div class="zero" />
+                    } else if n == 1 {
+                        <div class="one" />
+                    } else {
+                        <div class="many" />
+                    }
+                </div>
+            }
+        };
+        assert_eq!(
+            view(0),
+            html! {
+                <div>
+                    <><div class="zero" /></>
+                </div>
+            },
+        );
+        assert_eq!(
+            view(1),
+            html! {
+                <div>
+                    <><div class="one" /></>
+                </div>
+            },
+        );
+        assert_eq!(
+            view(2),
+            html! {
+                <div>
+                    <><div class="many" /></>
+                </div>
+            },
+        );
+    }
+
     #[test]
     fn html_if_option() {
         let option_foo = Some("foo");
@@ -3,7 +3,7 @@
 use gloo::utils::document;
 use web_sys::{Element, Text as TextNode};
 
-use super::{BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
+use super::{trace_dom, BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
 use crate::virtual_dom::{AttrValue, VText};
 
@@ -16,6 +16,7 @@ pub(super) struct BText {
 impl ReconcileTarget for BText {
     fn detach(self, _root: &BSubtree, parent: &Element, parent_to_detach: bool) {
         if !parent_to_detach {
+            trace_dom!(nodes_removed; dom.op = "remove_node");
             let result = parent.remove_child(&self.text_node);
 
             if result.is_err() {
@@ -42,6 +43,7 @@ impl Reconcilable for VText {
         slot: DomSlot,
     ) -> (DomSlot, Self::Bundle) {
         let Self { text } = self;
+        trace_dom!(nodes_created; dom.op = "create_text");
         let text_node = document().create_text_node(&text);
         slot.insert(parent, &text_node);
         let node_ref = DomSlot::at(text_node.clone().into());
@@ -74,6 +76,7 @@ impl Reconcilable for VText {
         let Self { text } = self;
         let ancestor_text = std::mem::replace(&mut btext.text, text);
         if btext.text != ancestor_text {
+            trace_dom!(text_updates; dom.op = "update_text");
             btext.text_node.set_node_value(Some(&btext.text));
         }
         DomSlot::at(btext.text_node.clone().into())
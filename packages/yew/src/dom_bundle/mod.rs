@@ -4,6 +4,10 @@
 //! In our case, the base space is the virtual dom we're trying to render.
 //! In order to efficiently implement updates, and diffing, additional information has to be
 //! kept around. This information is carried in the bundle.
+//!
+//! With the `trace_dom` feature, the operations performed on the DOM while rendering emit a `TRACE`
+//! event with a `dom.op` field naming them, which allows counting them with a `tracing` subscriber,
+//! for example per run of the scheduler. Hydration does not emit them.
 
 use web_sys::Element;
 
@@ -19,6 +23,8 @@ mod bsuspense;
 mod btag;
 mod btext;
 mod position;
+#[cfg(feature = "trace_dom")]
+pub(crate) mod stats;
 mod subtree_root;
 
 mod traits;
@@ -33,10 +39,12 @@ use bsuspense::BSuspense;
 use btag::{BTag, Registry};
 use btext::BText;
 pub(crate) use position::{DomSlot, DynamicDomSlot};
+#[cfg(feature = "trace_dom")]
+pub use stats::DomStats;
 use subtree_root::EventDescriptor;
 pub use subtree_root::{set_event_bubbling, BSubtree};
 use traits::{Reconcilable, ReconcileTarget};
-use utils::test_log;
+use utils::{test_log, trace_dom};
 
 /// A Bundle.
 ///
@@ -5,6 +5,8 @@ use std::rc::Rc;
 
 use web_sys::{Element, Node};
 
+use super::trace_dom;
+
 /// A position in the list of children of an implicit parent [`Element`].
 ///
 /// This can either be in front of a `DomSlot::at(next_sibling)`, at the end of the list with
@@ -105,6 +107,7 @@ impl DomSlot {
     /// Insert a [Node] at the position denoted by this slot. `parent` must be the actual parent
     /// element of the children that this slot is implicitly a part of.
     pub(super) fn insert(&self, parent: &Element, node: &Node) {
+        trace_dom!(dom.op = "insert_node");
         self.with_next_sibling(|next_sibling| {
             parent
                 .insert_before(node, next_sibling)
@@ -124,6 +127,18 @@ impl DomSlot {
         });
     }
 
+    /// Whether both slots are known to denote the same position, because they are in front of
+    /// the same node or chained to the same [`DynamicDomSlot`].
+    pub(super) fn is_same(&self, other: &Self) -> bool {
+        match (&self.variant, &other.variant) {
+            (DomSlotVariant::Node(l), DomSlotVariant::Node(r)) => l == r,
+            (DomSlotVariant::Chained(l), DomSlotVariant::Chained(r)) => {
+                Rc::ptr_eq(&l.target, &r.target)
+            }
+            _ => false,
+        }
+    }
+
     #[cfg(target_arch = "wasm32")]
     #[cfg(test)]
     fn get(&self) -> Option<Node> {
@@ -0,0 +1,74 @@
+//! Counting the operations a render performs on the DOM, see [`DomStats`].
+
+use std::cell::{Cell, RefCell};
+
+use crate::callback::Callback;
+
+/// The number of operations Yew performed on the DOM during one run of the scheduler.
+///
+/// All renders that are pending are applied within one run, so with a single application this
+/// covers one update of it. The stats are passed to the callback set with
+/// [`Renderer::dom_stats_callback`](crate::Renderer::dom_stats_callback).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DomStats {
+    /// Elements and text nodes that were created.
+    pub nodes_created: usize,
+    /// Nodes that were removed from the DOM.
+    pub nodes_removed: usize,
+    /// Attributes, properties, classes and style declarations that were set.
+    pub attributes_set: usize,
+    /// Attributes, classes and style declarations that were removed.
+    pub attributes_removed: usize,
+    /// Event listeners that were attached to elements.
+    pub listeners_attached: usize,
+    /// Event listeners that were detached from elements.
+    pub listeners_detached: usize,
+    /// Text nodes whose contents were changed.
+    pub text_updates: usize,
+}
+
+thread_local! {
+    static STATS: Cell<DomStats> = Cell::default();
+    static CALLBACKS: RefCell<Vec<(usize, Callback<DomStats>)>> = RefCell::default();
+}
+
+/// Adds to the stats of the current run of the scheduler.
+#[inline]
+pub(crate) fn count(f: impl FnOnce(&mut DomStats)) {
+    STATS.with(|stats| {
+        let mut current = stats.get();
+        f(&mut current);
+        stats.set(current);
+    });
+}
+
+/// Registers the callback of the application with the given id.
+pub(crate) fn set_callback(app_id: usize, callback: Callback<DomStats>) {
+    CALLBACKS.with(|callbacks| callbacks.borrow_mut().push((app_id, callback)));
+}
+
+/// Removes the callback of the application with the given id.
+pub(crate) fn remove_callback(app_id: usize) {
+    CALLBACKS.with(|callbacks| callbacks.borrow_mut().retain(|(id, _)| *id != app_id));
+}
+
+/// Passes the stats collected since the last report to every callback, unless nothing was
+/// done to the DOM.
+pub(crate) fn report() {
+    let stats = STATS.with(|stats| stats.take());
+    if stats == DomStats::default() {
+        return;
+    }
+    // Cloned, so that the callbacks can render new applications
+    let callbacks = CALLBACKS.with(|callbacks| {
+        callbacks
+            .borrow()
+            .iter()
+            .map(|(_, callback)| callback.clone())
+            .collect::<Vec<_>>()
+    });
+    for callback in callbacks {
+        callback.emit(stats);
+    }
+}
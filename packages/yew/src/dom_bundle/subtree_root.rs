@@ -13,7 +13,7 @@ use web_sys::{
     AddEventListenerOptions, Element, Event, EventTarget as HtmlEventTarget, ShadowRoot,
 };
 
-use super::{test_log, Registry};
+use super::{test_log, trace_dom, Registry};
 use crate::virtual_dom::{Listener, ListenerKind};
 
 /// DOM-Types that capture (bubbling) events. This generally includes event targets,
@@ -155,6 +155,7 @@ impl EventListener {
         let mut options = AddEventListenerOptions::new();
         options.capture(true).passive(desc.passive);
 
+        trace_dom!(dom.op = "add_event_listener");
         target
             .add_event_listener_with_callback_and_add_event_listener_options(
                 intern(&event_type),
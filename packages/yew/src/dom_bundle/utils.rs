@@ -15,6 +15,29 @@ macro_rules! test_log {
 /// Set RUSTFLAGS="--cfg verbose_tests" environment variable to activate.
 pub(super) use test_log;
 
+#[cfg(feature = "trace_dom")]
+macro_rules! trace_dom {
+    ($stat:ident += $n:expr; $($arg:tt)*) => {
+        $crate::dom_bundle::stats::count(|stats| stats.$stat += $n);
+        ::tracing::trace!($($arg)*)
+    };
+    ($stat:ident; $($arg:tt)*) => {
+        $crate::dom_bundle::stats::count(|stats| stats.$stat += 1);
+        ::tracing::trace!($($arg)*)
+    };
+    ($($arg:tt)*) => {
+        ::tracing::trace!($($arg)*)
+    };
+}
+#[cfg(not(feature = "trace_dom"))]
+macro_rules! trace_dom {
+    ($($arg:tt)*) => {};
+}
+/// Emit a `TRACE` event for an operation on the DOM, optionally counting it in the named field
+/// of the [`DomStats`](super::DomStats) of the render.
+/// Only active with the `trace_dom` feature, so hot paths pay nothing otherwise.
+pub(super) use trace_dom;
+
 #[cfg(feature = "hydration")]
 mod feat_hydration {
     use std::borrow::Cow;
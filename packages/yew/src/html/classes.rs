@@ -71,6 +71,37 @@ impl Classes {
         }
     }
 
+    /// Adds a class to a set if the condition is `true`.
+    ///
+    /// If the provided class has already been added, this method will ignore it.
+    pub fn push_if<T: Into<Self>>(&mut self, class: T, condition: bool) {
+        if condition {
+            self.push(class);
+        }
+    }
+
+    /// Adds a class to a set and returns the set, so that calls can be chained.
+    ///
+    /// ```
+    /// # use yew::Classes;
+    /// let editing = false;
+    /// let classes = Classes::new().with("todo").with_if("editing", editing);
+    /// assert_eq!(classes.to_string(), "todo");
+    /// ```
+    #[must_use]
+    pub fn with<T: Into<Self>>(mut self, class: T) -> Self {
+        self.push(class);
+        self
+    }
+
+    /// Adds a class to a set if the condition is `true` and returns the set, so that calls can be
+    /// chained.
+    #[must_use]
+    pub fn with_if<T: Into<Self>>(mut self, class: T, condition: bool) -> Self {
+        self.push_if(class, condition);
+        self
+    }
+
     /// Adds a class to a set.
     ///
     /// If the provided class has already been added, this method will ignore it.
@@ -274,6 +305,34 @@ impl<T: Into<Classes> + Clone> From<&[T]> for Classes {
     }
 }
 
+impl<T: Into<Classes>, const SIZE: usize> From<[T; SIZE]> for Classes {
+    fn from(t: [T; SIZE]) -> Self {
+        Self::from_iter(t)
+    }
+}
+
+macro_rules! impl_from_tuple {
+    ($($ty:ident)*) => {
+        impl<$($ty: Into<Classes>),*> From<($($ty,)*)> for Classes {
+            #[allow(non_snake_case)]
+            fn from(($($ty,)*): ($($ty,)*)) -> Self {
+                let mut classes = Self::new();
+                $(classes.push($ty);)*
+                classes
+            }
+        }
+    };
+}
+
+impl_from_tuple!(A);
+impl_from_tuple!(A B);
+impl_from_tuple!(A B C);
+impl_from_tuple!(A B C D);
+impl_from_tuple!(A B C D E);
+impl_from_tuple!(A B C D E F);
+impl_from_tuple!(A B C D E F G);
+impl_from_tuple!(A B C D E F G H);
+
 impl PartialEq for Classes {
     fn eq(&self, other: &Self) -> bool {
         self.set.len() == other.set.len() && self.set.iter().eq(other.set.iter())
@@ -371,10 +430,79 @@ mod tests {
         assert!(subject.contains("bar"));
     }
 
+    #[test]
+    fn can_be_created_from_array() {
+        let subject = Classes::from(["foo", "bar"]);
+        assert!(subject.contains("foo"));
+        assert!(subject.contains("bar"));
+    }
+
+    #[test]
+    fn can_be_created_from_tuple() {
+        let completed: Option<&'static str> = None;
+        let subject = Classes::from(("todo", String::from("editing"), completed));
+        assert_eq!(subject.to_string(), "todo editing");
+    }
+
     #[test]
     fn ignores_empty_string() {
         let classes = String::from("");
         let subject = Classes::from(classes);
         assert!(subject.is_empty())
     }
+
+    #[test]
+    fn ignores_empty_and_whitespace_pushes() {
+        let mut subject = Classes::from("todo");
+        subject.push("");
+        subject.push("   ");
+        subject.push(String::new());
+        assert_eq!(subject.to_string(), "todo");
+    }
+
+    #[test]
+    fn duplicates_collapse() {
+        let mut subject = Classes::new();
+        subject.push("todo");
+        subject.push("todo completed");
+        subject.push(String::from("completed"));
+        assert_eq!(subject.to_string(), "todo completed");
+    }
+
+    #[test]
+    fn conditional_push() {
+        let (completed, editing) = (true, false);
+        let mut subject = Classes::from("todo");
+        subject.push(if completed { Some("completed") } else { None });
+        subject.push(if editing { Some("editing") } else { None });
+        assert_eq!(subject.to_string(), "todo completed");
+    }
+
+    #[test]
+    fn push_if_only_adds_when_true() {
+        let mut subject = Classes::from("todo");
+        subject.push_if("completed", true);
+        subject.push_if("editing", false);
+        assert_eq!(subject.to_string(), "todo completed");
+    }
+
+    #[test]
+    fn can_be_built_by_chaining() {
+        let subject = Classes::new()
+            .with("todo")
+            .with_if("completed", true)
+            .with_if("editing", false)
+            .with(vec!["todo", "last"]);
+        assert_eq!(subject.to_string(), "todo completed last");
+    }
+
+    #[test]
+    fn can_be_extended_in_a_loop() {
+        let mut subject = Classes::new();
+        for level in 1..=3 {
+            subject.extend([format!("level-{}", level)]);
+        }
+        subject.extend(["level-1", "last"]);
+        assert_eq!(subject.to_string(), "level-1 level-2 level-3 last");
+    }
 }
@@ -205,7 +205,8 @@ where
             _ => return false,
         };
 
-        if self.context.props != props {
+        // Reusing the same `Html` shares the props, which makes comparing them unnecessary
+        if !Rc::ptr_eq(&self.context.props, &props) && self.context.props != props {
             let old_props = std::mem::replace(&mut self.context.props, props);
             self.component.changed(&self.context, &old_props)
         } else {
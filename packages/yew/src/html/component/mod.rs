@@ -173,6 +173,10 @@ pub trait Component: Sized + 'static {
     /// Note that `rendered()` calls do not always follow a render request from `update()` or
     /// `changed()`. Yew may optimize some calls out to reduce virtual DOM tree generation overhead.
     /// The `create()` call is always followed by a call to `view()` and later `rendered()`.
+    ///
+    /// `first_render` is `true` exactly once, after the component was first mounted. Its nodes are
+    /// in the DOM at that point, so this is the place to set focus through a
+    /// [`NodeRef`](crate::NodeRef) or to send a message that starts the initial work.
     #[allow(unused_variables)]
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {}
 
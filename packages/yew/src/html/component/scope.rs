@@ -7,6 +7,8 @@ use std::ops::Deref;
 use std::rc::Rc;
 use std::{fmt, iter};
 
+#[cfg(any(feature = "csr", feature = "ssr"))]
+use futures::future::{AbortHandle, Abortable};
 use futures::{Stream, StreamExt};
 
 #[cfg(any(feature = "csr", feature = "ssr"))]
@@ -101,6 +103,11 @@ pub struct Scope<COMP: BaseComponent> {
     #[cfg(any(feature = "csr", feature = "ssr"))]
     pub(crate) state: Shared<Option<ComponentState>>,
 
+    /// Handles to abort the futures spawned by [`Scope::send_future_until_destroyed`], or `None`
+    /// once the component was destroyed.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pending_futures: Shared<Option<Vec<AbortHandle>>>,
+
     pub(crate) id: usize,
 }
 
@@ -122,6 +129,9 @@ impl<COMP: BaseComponent> Clone for Scope<COMP> {
             #[cfg(any(feature = "csr", feature = "ssr"))]
             state: self.state.clone(),
 
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            pending_futures: self.pending_futures.clone(),
+
             id: self.id,
         }
     }
@@ -187,6 +197,9 @@ impl<COMP: BaseComponent> Scope<COMP> {
     /// This method asynchronously awaits a [Future] that returns a message and sends it
     /// to the linked component.
     ///
+    /// The future keeps running if the component is destroyed before it resolves, only its
+    /// message is discarded. Use [`Scope::send_future_until_destroyed`] to drop it instead.
+    ///
     /// # Panics
     /// If the future panics, then the promise will not resolve, and will leak.
     pub fn send_future<Fut, Msg>(&self, future: Fut)
@@ -201,6 +214,40 @@ impl<COMP: BaseComponent> Scope<COMP> {
         });
     }
 
+    /// Like [`Scope::send_future`], but the future is dropped when the component is destroyed,
+    /// without being polled again.
+    ///
+    /// A future that is passed in after the component started being destroyed, for example from
+    /// [`Component::destroy`](crate::html::Component::destroy), is dropped right away.
+    ///
+    /// # Panics
+    /// If the future panics, then the promise will not resolve, and will leak.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub fn send_future_until_destroyed<Fut, Msg>(&self, future: Fut)
+    where
+        Msg: Into<COMP::Message>,
+        Fut: Future<Output = Msg> + 'static,
+    {
+        let (handle, registration) = AbortHandle::new_pair();
+        let finished = handle.clone();
+        match self.pending_futures.borrow_mut().as_mut() {
+            Some(pending) => {
+                // The handles of futures that completed are aborted as well, so that they can be
+                // told apart from the ones that are still running.
+                pending.retain(|handle| !handle.is_aborted());
+                pending.push(handle);
+            }
+            None => return,
+        }
+        let link = self.clone();
+        spawn_local(async move {
+            if let Ok(message) = Abortable::new(future, registration).await {
+                link.send_message(message.into());
+            }
+            finished.abort();
+        });
+    }
+
     /// This method creates a [`Callback`] which, when emitted, asynchronously awaits the
     /// message returned from the passed function before sending it to the linked component.
     ///
@@ -267,6 +314,16 @@ impl<COMP: BaseComponent> Scope<COMP> {
         spawn_local(js_future);
     }
 
+    /// Aborts the futures passed to [`Scope::send_future_until_destroyed`], and any passed
+    /// afterwards.
+    #[cfg(feature = "csr")]
+    fn abort_pending_futures(&self) {
+        let pending = self.pending_futures.borrow_mut().take();
+        for handle in pending.into_iter().flatten() {
+            handle.abort();
+        }
+    }
+
     /// Returns the linked component if available
     pub fn get_component(&self) -> Option<impl Deref<Target = COMP> + '_> {
         self.arch_get_component()
@@ -448,6 +505,8 @@ mod feat_csr_ssr {
                 state,
                 parent,
 
+                pending_futures: Rc::new(RefCell::new(Some(Vec::new()))),
+
                 id: COMP_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
             }
         }
@@ -627,6 +686,7 @@ mod feat_csr {
 
         /// Process an event to destroy a component
         fn destroy(self, parent_to_detach: bool) {
+            self.abort_pending_futures();
             scheduler::push_component_destroy(Box::new(DestroyRunner {
                 state: self.state,
                 parent_to_detach,
@@ -174,12 +174,44 @@ impl_into_prop!(|value: &'static str| -> String { value.to_owned() });
 
 impl_into_prop!(|value: &'static str| -> AttrValue { AttrValue::Static(value) });
 impl_into_prop!(|value: String| -> AttrValue { AttrValue::Rc(Rc::from(value)) });
+impl_into_prop!(|value: &String| -> AttrValue { AttrValue::Rc(Rc::from(value.as_str())) });
 impl_into_prop!(|value: Rc<str>| -> AttrValue { AttrValue::Rc(value) });
 impl_into_prop!(|value: VNode| -> Children { Children::new(vec![value]) });
 impl_into_prop!(|value: &'static str| -> VNode { crate::html!(value) });
 impl_into_prop!(|value: String| -> VNode { crate::html!(value) });
 impl_into_prop!(|value: AttrValue| -> VNode { crate::html!(value) });
 
+macro_rules! impl_into_prop_for_numbers {
+    ($($ty:ty),*) => {
+        $(
+            impl_into_prop!(|value: $ty| -> AttrValue { AttrValue::Rc(Rc::from(value.to_string())) });
+        )*
+    };
+}
+
+macro_rules! impl_into_prop_for_floats {
+    ($($ty:ty),*) => {
+        $(
+            impl_into_prop!(|value: $ty| -> AttrValue {
+                // Rust would render these as "inf", "-inf" and "NaN", unlike JavaScript
+                if value.is_nan() {
+                    AttrValue::Static("NaN")
+                } else if value == <$ty>::INFINITY {
+                    AttrValue::Static("Infinity")
+                } else if value == <$ty>::NEG_INFINITY {
+                    AttrValue::Static("-Infinity")
+                } else {
+                    AttrValue::Rc(Rc::from(value.to_string()))
+                }
+            });
+        )*
+    };
+}
+
+// `ToString` doesn't depend on the locale, so `0.5` is always rendered as "0.5"
+impl_into_prop_for_numbers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_into_prop_for_floats!(f32, f64);
+
 impl<T: ImplicitClone + 'static> IntoPropValue<IArray<T>> for &'static [T] {
     fn into_prop_value(self) -> IArray<T> {
         IArray::from(self)
@@ -219,6 +251,35 @@ mod test {
         let _: AttrValue = "foo".into_prop_value();
         let _: Option<AttrValue> = "foo".into_prop_value();
         let _: Option<AttrValue> = Rc::<str>::from("foo").into_prop_value();
+
+        let string = String::from("foo");
+        let _: AttrValue = (&string).into_prop_value();
+        let _: Option<AttrValue> = (&string).into_prop_value();
+        let _: Option<AttrValue> = Some(&string).into_prop_value();
+    }
+
+    #[test]
+    fn test_numbers() {
+        let value: AttrValue = 120.into_prop_value();
+        assert_eq!(value, "120");
+        let value: Option<AttrValue> = 42usize.into_prop_value();
+        assert_eq!(value.as_deref(), Some("42"));
+        let value: Option<AttrValue> = Some(-1i64).into_prop_value();
+        assert_eq!(value.as_deref(), Some("-1"));
+        let value: AttrValue = 0.5f64.into_prop_value();
+        assert_eq!(value, "0.5");
+        let value: AttrValue = 2.0f32.into_prop_value();
+        assert_eq!(value, "2");
+    }
+
+    #[test]
+    fn test_non_finite_floats() {
+        let value: AttrValue = f64::INFINITY.into_prop_value();
+        assert_eq!(value, "Infinity");
+        let value: Option<AttrValue> = f32::NEG_INFINITY.into_prop_value();
+        assert_eq!(value.as_deref(), Some("-Infinity"));
+        let value: AttrValue = f64::NAN.into_prop_value();
+        assert_eq!(value, "NaN");
     }
 
     #[test]
@@ -142,6 +142,10 @@ impl_short! {
     ondragstart(DragEvent)
     ondrop(DragEvent)
 
+    oncompositionend(CompositionEvent)
+    oncompositionstart(CompositionEvent)
+    oncompositionupdate(CompositionEvent)
+
     onblur(FocusEvent)
     onfocus(FocusEvent)
     onfocusin(FocusEvent)
@@ -5,6 +5,7 @@ mod component;
 mod conversion;
 mod error;
 mod listener;
+mod style;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -14,6 +15,7 @@ pub use component::*;
 pub use conversion::*;
 pub use error::*;
 pub use listener::*;
+pub use style::*;
 use wasm_bindgen::JsValue;
 use web_sys::{Element, Node};
 
@@ -0,0 +1,355 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+use implicit_clone::ImplicitClone;
+use indexmap::IndexMap;
+
+use super::IntoPropValue;
+use crate::virtual_dom::AttrValue;
+
+/// A list of inline CSS declarations, cheap to clone.
+///
+/// It is rendered as the value of the `style` attribute. Setting a property that is already
+/// present replaces its value but keeps its position.
+///
+/// ```
+/// use yew::html::Style;
+/// use yew::prelude::*;
+///
+/// let color = "red";
+/// let style = Style::from([("color", color), ("display", "flex")]);
+///
+/// html! { <div {style}></div> };
+///
+/// // Lists of pairs can also be passed to the attribute directly
+/// html! { <div style={vec![("color", color), ("display", "flex")]}></div> };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Style {
+    declarations: Rc<IndexMap<AttrValue, AttrValue>>,
+}
+
+impl ImplicitClone for Style {}
+
+// The order of the declarations matters, as it is kept when rendering them and later declarations
+// can override earlier ones.
+impl PartialEq for Style {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.declarations, &other.declarations)
+            || self.declarations.iter().eq(other.declarations.iter())
+    }
+}
+
+impl Eq for Style {}
+
+/// Splits `s` at the first `separator` that is neither quoted nor inside of parentheses, such as
+/// the `;` in `url(data:image/png;base64,AAA=)`.
+fn split_top_level(s: &str, separator: char) -> Option<(&str, &str)> {
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (quote, c) {
+            (_, '\\') => escaped = true,
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, c) if c == separator && depth == 0 => {
+                return Some((&s[..i], &s[i + c.len_utf8()..]))
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits a declaration block like `color: red; display: flex` into its properties and values.
+/// Declarations without a colon are skipped.
+pub(crate) fn parse_declarations(s: &str) -> impl Iterator<Item = (&str, &str)> {
+    let mut rest = Some(s);
+    std::iter::from_fn(move || {
+        let block = rest?;
+        Some(match split_top_level(block, ';') {
+            Some((declaration, next)) => {
+                rest = Some(next);
+                declaration
+            }
+            None => {
+                rest = None;
+                block
+            }
+        })
+    })
+    .filter_map(|declaration| split_top_level(declaration, ':'))
+    .map(|(property, value)| (property.trim(), value.trim()))
+    .filter(|(property, _)| !property.is_empty())
+}
+
+impl Style {
+    /// Creates an empty list of declarations.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            declarations: Rc::new(IndexMap::new()),
+        }
+    }
+
+    /// Sets a property to the given value.
+    ///
+    /// If the property has already been set, its value is replaced.
+    pub fn set<P: Into<AttrValue>, V: Into<AttrValue>>(&mut self, property: P, value: V) {
+        Rc::make_mut(&mut self.declarations).insert(property.into(), value.into());
+    }
+
+    /// Removes a property, returning its previous value.
+    pub fn remove<P: AsRef<str>>(&mut self, property: P) -> Option<AttrValue> {
+        if !self.declarations.contains_key(property.as_ref()) {
+            return None;
+        }
+        Rc::make_mut(&mut self.declarations).shift_remove(property.as_ref())
+    }
+
+    /// Returns the value of a property, if it is set.
+    #[inline]
+    pub fn get<P: AsRef<str>>(&self, property: P) -> Option<&AttrValue> {
+        self.declarations.get(property.as_ref())
+    }
+
+    /// Check the list of declarations is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (property, value)) in self.declarations.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{property}: {value};")?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoPropValue<AttrValue> for Style {
+    #[inline]
+    fn into_prop_value(self) -> AttrValue {
+        self.to_string().into()
+    }
+}
+
+impl IntoPropValue<Option<AttrValue>> for Style {
+    #[inline]
+    fn into_prop_value(self) -> Option<AttrValue> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.into_prop_value())
+        }
+    }
+}
+
+impl<P: Into<AttrValue>, V: Into<AttrValue>> IntoPropValue<AttrValue> for Vec<(P, V)> {
+    #[inline]
+    fn into_prop_value(self) -> AttrValue {
+        Style::from(self).into_prop_value()
+    }
+}
+
+impl<P: Into<AttrValue>, V: Into<AttrValue>> IntoPropValue<Option<AttrValue>> for Vec<(P, V)> {
+    #[inline]
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Style::from(self).into_prop_value()
+    }
+}
+
+impl<P: Into<AttrValue>, V: Into<AttrValue>, const SIZE: usize> IntoPropValue<AttrValue>
+    for [(P, V); SIZE]
+{
+    #[inline]
+    fn into_prop_value(self) -> AttrValue {
+        Style::from(self).into_prop_value()
+    }
+}
+
+impl<P: Into<AttrValue>, V: Into<AttrValue>, const SIZE: usize> IntoPropValue<Option<AttrValue>>
+    for [(P, V); SIZE]
+{
+    #[inline]
+    fn into_prop_value(self) -> Option<AttrValue> {
+        Style::from(self).into_prop_value()
+    }
+}
+
+impl<P: Into<AttrValue>, V: Into<AttrValue>> Extend<(P, V)> for Style {
+    fn extend<T: IntoIterator<Item = (P, V)>>(&mut self, iter: T) {
+        let declarations = Rc::make_mut(&mut self.declarations);
+        for (property, value) in iter {
+            declarations.insert(property.into(), value.into());
+        }
+    }
+}
+
+impl<P: Into<AttrValue>, V: Into<AttrValue>> FromIterator<(P, V)> for Style {
+    fn from_iter<T: IntoIterator<Item = (P, V)>>(iter: T) -> Self {
+        let mut style = Self::new();
+        style.extend(iter);
+        style
+    }
+}
+
+impl From<&'static str> for Style {
+    fn from(t: &'static str) -> Self {
+        parse_declarations(t)
+            .map(|(property, value)| (AttrValue::Static(property), AttrValue::Static(value)))
+            .collect()
+    }
+}
+
+impl From<String> for Style {
+    fn from(t: String) -> Self {
+        Self::from(&t)
+    }
+}
+
+impl From<&String> for Style {
+    fn from(t: &String) -> Self {
+        parse_declarations(t)
+            .map(|(property, value)| (property.to_owned(), value.to_owned()))
+            .collect()
+    }
+}
+
+impl From<AttrValue> for Style {
+    fn from(t: AttrValue) -> Self {
+        parse_declarations(&t)
+            .map(|(property, value)| (property.to_owned(), value.to_owned()))
+            .collect()
+    }
+}
+
+impl<P: Into<AttrValue>, V: Into<AttrValue>> From<Vec<(P, V)>> for Style {
+    fn from(t: Vec<(P, V)>) -> Self {
+        t.into_iter().collect()
+    }
+}
+
+impl<P: Into<AttrValue>, V: Into<AttrValue>, const SIZE: usize> From<[(P, V); SIZE]> for Style {
+    fn from(t: [(P, V); SIZE]) -> Self {
+        IntoIterator::into_iter(t).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_is_initially_empty() {
+        let subject = Style::new();
+        assert!(subject.is_empty());
+        assert_eq!(subject.to_string(), "");
+    }
+
+    #[test]
+    fn it_sets_value() {
+        let mut subject = Style::new();
+        subject.set("color", "red");
+        assert_eq!(subject.get("color").map(AttrValue::as_str), Some("red"));
+    }
+
+    #[test]
+    fn it_replaces_value_in_place() {
+        let mut subject = Style::from([("color", "red"), ("display", "flex")]);
+        subject.set("color", "blue");
+        assert_eq!(subject.to_string(), "color: blue; display: flex;");
+    }
+
+    #[test]
+    fn it_removes_value() {
+        let mut subject = Style::from([("color", "red"), ("display", "flex")]);
+        assert_eq!(
+            subject.remove("color").as_ref().map(AttrValue::as_str),
+            Some("red")
+        );
+        assert_eq!(subject.remove("color"), None);
+        assert_eq!(subject.to_string(), "display: flex;");
+    }
+
+    #[test]
+    fn it_parses_declaration_blocks() {
+        let subject = Style::from(" color: red;display:flex ; ;invalid");
+        assert_eq!(subject.to_string(), "color: red; display: flex;");
+
+        let subject = Style::from(String::from("background: url(\"a.png\")"));
+        assert_eq!(
+            subject.get("background").map(AttrValue::as_str),
+            Some("url(\"a.png\")")
+        );
+    }
+
+    #[test]
+    fn it_keeps_separators_in_parentheses_and_quotes() {
+        let subject = Style::from("background: url(data:image/png;base64,AAA=); color: red");
+        assert_eq!(
+            subject.to_string(),
+            "background: url(data:image/png;base64,AAA=); color: red;"
+        );
+
+        let subject = Style::from(String::from(r#"content: "a;b"; quotes: '\'' ';'"#));
+        assert_eq!(
+            subject.get("content").map(AttrValue::as_str),
+            Some(r#""a;b""#)
+        );
+        assert_eq!(
+            subject.get("quotes").map(AttrValue::as_str),
+            Some(r#"'\'' ';'"#)
+        );
+    }
+
+    #[test]
+    fn equality_depends_on_order() {
+        let subject = Style::from([("a", "1"), ("b", "2")]);
+        assert_eq!(subject, Style::from([("a", "1"), ("b", "2")]));
+        assert_ne!(subject, Style::from([("b", "2"), ("a", "1")]));
+    }
+
+    #[test]
+    fn can_be_created_from_vec() {
+        let color = String::from("red");
+        let subject = Style::from(vec![("color", color)]);
+        assert_eq!(subject.to_string(), "color: red;");
+    }
+
+    #[test]
+    fn pair_lists_convert_to_attribute_values() {
+        let value: Option<AttrValue> =
+            vec![("color", "red"), ("display", "flex")].into_prop_value();
+        assert_eq!(value, Some(AttrValue::from("color: red; display: flex;")));
+
+        let value: AttrValue = [("color", String::from("red"))].into_prop_value();
+        assert_eq!(value, AttrValue::from("color: red;"));
+
+        let value: Option<AttrValue> = Vec::<(&str, &str)>::new().into_prop_value();
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn empty_style_omits_attribute() {
+        let value: Option<AttrValue> = Style::new().into_prop_value();
+        assert_eq!(value, None);
+
+        let value: Option<AttrValue> = Style::from("color: red").into_prop_value();
+        assert_eq!(value, Some(AttrValue::from("color: red;")));
+    }
+}
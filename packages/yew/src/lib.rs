@@ -27,6 +27,8 @@
 //!   are making a Yew application (not a library).
 //! - `ssr`: Enables Server-side Rendering support and [`ServerRenderer`].
 //! - `hydration`: Enables Hydration support.
+//! - `trace_dom`: Counts the operations performed on the DOM while rendering, see [`DomStats`], and
+//!   emits a `tracing` event for each of them.
 //!
 //! ## Example
 //!
@@ -303,9 +305,9 @@ pub mod tests;
 pub mod events {
     #[doc(no_inline)]
     pub use web_sys::{
-        AnimationEvent, DragEvent, ErrorEvent, Event, FocusEvent, InputEvent, KeyboardEvent,
-        MouseEvent, PointerEvent, ProgressEvent, SubmitEvent, TouchEvent, TransitionEvent, UiEvent,
-        WheelEvent,
+        AnimationEvent, CompositionEvent, DragEvent, ErrorEvent, Event, FocusEvent, InputEvent,
+        KeyboardEvent, MouseEvent, PointerEvent, ProgressEvent, SubmitEvent, TouchEvent,
+        TransitionEvent, UiEvent, WheelEvent,
     };
 
     #[cfg(feature = "csr")]
@@ -315,6 +317,8 @@ pub mod events {
 
 #[cfg(feature = "csr")]
 pub use crate::app_handle::AppHandle;
+#[cfg(feature = "trace_dom")]
+pub use crate::dom_bundle::DomStats;
 #[cfg(feature = "csr")]
 pub use crate::renderer::{set_custom_panic_hook, Renderer};
 
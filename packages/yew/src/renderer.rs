@@ -5,6 +5,10 @@ use std::rc::Rc;
 use web_sys::Element;
 
 use crate::app_handle::AppHandle;
+#[cfg(feature = "trace_dom")]
+use crate::callback::Callback;
+#[cfg(feature = "trace_dom")]
+use crate::dom_bundle::{stats, DomStats};
 use crate::html::BaseComponent;
 
 thread_local! {
@@ -38,6 +42,8 @@ where
 {
     root: Element,
     props: COMP::Properties,
+    #[cfg(feature = "trace_dom")]
+    dom_stats: Option<Callback<DomStats>>,
 }
 
 impl<COMP> Default for Renderer<COMP>
@@ -83,13 +89,36 @@ where
 
     /// Creates a [Renderer] that renders into a custom root with custom properties.
     pub fn with_root_and_props(root: Element, props: COMP::Properties) -> Self {
-        Self { root, props }
+        Self {
+            root,
+            props,
+            #[cfg(feature = "trace_dom")]
+            dom_stats: None,
+        }
+    }
+
+    /// Sets a callback that receives the [`DomStats`] of every run of the scheduler that changed
+    /// the DOM, until the application is destroyed.
+    ///
+    /// The stats count the operations of all applications rendered on the page, as their renders
+    /// are applied together.
+    #[cfg(feature = "trace_dom")]
+    pub fn dom_stats_callback(mut self, callback: Callback<DomStats>) -> Self {
+        self.dom_stats = Some(callback);
+        self
     }
 
     /// Renders the application.
     pub fn render(self) -> AppHandle<COMP> {
         set_default_panic_hook();
-        AppHandle::<COMP>::mount_with_props(self.root, Rc::new(self.props))
+        #[cfg(feature = "trace_dom")]
+        let dom_stats = self.dom_stats;
+        let app = AppHandle::<COMP>::mount_with_props(self.root, Rc::new(self.props));
+        #[cfg(feature = "trace_dom")]
+        if let Some(callback) = dom_stats {
+            stats::set_callback(app.scope.id, callback);
+        }
+        app
     }
 }
 
@@ -104,7 +133,14 @@ mod feat_hydration {
         /// Hydrates the application.
         pub fn hydrate(self) -> AppHandle<COMP> {
             set_default_panic_hook();
-            AppHandle::<COMP>::hydrate_with_props(self.root, Rc::new(self.props))
+            #[cfg(feature = "trace_dom")]
+            let dom_stats = self.dom_stats;
+            let app = AppHandle::<COMP>::hydrate_with_props(self.root, Rc::new(self.props));
+            #[cfg(feature = "trace_dom")]
+            if let Some(callback) = dom_stats {
+                stats::set_callback(app.scope.id, callback);
+            }
+            app
         }
     }
 }
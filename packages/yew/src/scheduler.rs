@@ -216,6 +216,8 @@ pub(crate) fn start_now() {
     LOCK.with(|l| {
         if let Ok(_lock) = l.try_borrow_mut() {
             scheduler_loop();
+            #[cfg(feature = "trace_dom")]
+            crate::dom_bundle::stats::report();
         }
     });
 }
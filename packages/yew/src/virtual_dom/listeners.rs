@@ -127,6 +127,9 @@ gen_listener_kinds! {
     oncopy
     oncut
     onpaste
+    oncompositionend
+    oncompositionstart
+    oncompositionupdate
     onanimationcancel
     onanimationend
     onanimationiteration
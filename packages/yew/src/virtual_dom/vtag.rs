@@ -21,6 +21,12 @@ pub const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
 /// Default namespace for html elements
 pub const HTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
 
+/// XLink namespace string used for `xlink:` prefixed attributes
+pub const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+
+/// XML namespace string used for `xml:` prefixed attributes
+pub const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
 /// Value field corresponding to an [Element]'s `value` property
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct Value<T>(Option<AttrValue>, PhantomData<T>);
@@ -621,4 +627,146 @@ mod ssr_tests {
 
         assert_eq!(s, r#"<textarea>teststring</textarea>"#);
     }
+
+    #[test]
+    async fn test_void_elements() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! { <p>{"a"}<br />{"b"}<hr /><img src="a.png" /></p> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, r#"<p>a<br>b<hr><img src="a.png"></p>"#);
+    }
+
+    #[test]
+    async fn test_escaped_attr_and_content() {
+        #[function_component]
+        fn Comp() -> Html {
+            let title = r#""a" & <b>"#;
+            let content = "<script>alert(1)</script>";
+            html! { <div {title}>{content}</div> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<div title="&quot;a&quot; &amp; &lt;b&gt;">&lt;script&gt;alert(1)&lt;/script&gt;</div>"#
+        );
+    }
+
+    #[test]
+    async fn test_attribute_expressions() {
+        #[function_component]
+        fn Comp() -> Html {
+            let size = "large";
+            let label = String::from("Entry");
+            html! {
+                <div
+                    class={format!("item item--{}", size)}
+                    title={&label}
+                    data-label={label.to_lowercase()}
+                />
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<div class="item item--large" title="Entry" data-label="entry"></div>"#
+        );
+    }
+
+    #[test]
+    async fn test_accessibility_attributes() {
+        #[function_component]
+        fn Comp() -> Html {
+            html! {
+                <a role="button" tabindex=0 aria-pressed="false" data-count=1000000 hidden=false>
+                    {"All"}
+                </a>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<a role="button" tabindex="0" aria-pressed="false" data-count="1000000">All</a>"#
+        );
+    }
+
+    #[test]
+    async fn test_numeric_attributes() {
+        #[function_component]
+        fn Comp() -> Html {
+            let width = 120;
+            let opacity = 0.5;
+            let count: Option<usize> = Some(3);
+            html! { <div width={width} data-opacity={opacity} data-count={count} /> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<div width="120" data-opacity="0.5" data-count="3"></div>"#
+        );
+    }
+
+    #[test]
+    async fn test_optional_boolean_attributes() {
+        #[function_component]
+        fn Comp() -> Html {
+            let disabled = Some(true);
+            let hidden: Option<bool> = None;
+            html! {
+                <input checked={Some(true)} {disabled} {hidden} required={Some(false)} readonly=true />
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            r#"<input checked disabled="disabled" readonly="readonly">"#
+        );
+    }
+
+    #[test]
+    async fn test_listeners_are_skipped() {
+        #[function_component]
+        fn Comp() -> Html {
+            let onclick = Callback::from(|_: MouseEvent| ());
+            html! { <button class="btn" {onclick}>{"Click"}</button> }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, r#"<button class="btn">Click</button>"#);
+    }
 }
@@ -0,0 +1,72 @@
+#![cfg(feature = "trace_dom")]
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use common::output_element;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::DomStats;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn dom_stats_are_reported_for_every_render() {
+    #[function_component]
+    fn Counter() -> Html {
+        let count = use_state(|| 0);
+        let onclick = {
+            let count = count.clone();
+            Callback::from(move |_| count.set(*count + 1))
+        };
+        let title = (*count > 0).then(|| "clicked");
+
+        html! {
+            <button id="counter" {title} {onclick}>{ *count }</button>
+        }
+    }
+
+    let reported = Rc::new(RefCell::new(Vec::<DomStats>::new()));
+    let app = yew::Renderer::<Counter>::with_root(output_element())
+        .dom_stats_callback({
+            let reported = reported.clone();
+            Callback::from(move |stats| reported.borrow_mut().push(stats))
+        })
+        .render();
+    sleep(Duration::ZERO).await;
+
+    let stats = std::mem::take(&mut *reported.borrow_mut());
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].nodes_created, 2);
+    assert_eq!(stats[0].attributes_set, 1);
+    assert_eq!(stats[0].listeners_attached, 1);
+    assert_eq!(stats[0].text_updates, 0);
+
+    gloo::utils::document()
+        .get_element_by_id("counter")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+    sleep(Duration::ZERO).await;
+
+    let stats = std::mem::take(&mut *reported.borrow_mut());
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].nodes_created, 0);
+    assert_eq!(stats[0].nodes_removed, 0);
+    // Only the new `title`, not the unchanged `id`
+    assert_eq!(stats[0].attributes_set, 1);
+    assert_eq!(stats[0].text_updates, 1);
+    // The callback is created anew on every render
+    assert_eq!(stats[0].listeners_detached, 1);
+    assert_eq!(stats[0].listeners_attached, 1);
+
+    app.destroy();
+    sleep(Duration::ZERO).await;
+    assert!(reported.borrow().is_empty());
+}
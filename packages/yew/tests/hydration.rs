@@ -1028,3 +1028,50 @@ async fn hydrate_empty() {
     let result = obtain_result_by_id("output");
     assert_eq!(result.as_str(), r#"<div>after</div><div>after</div>"#);
 }
+
+#[wasm_bindgen_test]
+async fn hydration_adopts_server_nodes_and_patches_differences() {
+    #[derive(Properties, PartialEq)]
+    struct Props {
+        name: AttrValue,
+    }
+
+    #[function_component]
+    fn App(props: &Props) -> Html {
+        html! {
+            <div>
+                <button title={&props.name}>{"Hello, "}{&props.name}</button>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::with_props(|| Props {
+        name: "server".into(),
+    })
+    .render()
+    .await;
+
+    let output = gloo::utils::document().get_element_by_id("output").unwrap();
+    output.set_inner_html(&s);
+    let server_button: web_sys::Node = output.query_selector("button").unwrap().unwrap().into();
+
+    sleep(Duration::ZERO).await;
+
+    Renderer::<App>::with_root_and_props(
+        output.clone(),
+        Props {
+            name: "client".into(),
+        },
+    )
+    .hydrate();
+
+    sleep(Duration::ZERO).await;
+
+    // The element created by the server is kept, only its content is updated to match the client
+    let button = output.query_selector("button").unwrap().unwrap();
+    assert!(button.is_same_node(Some(&server_button)));
+    assert_eq!(
+        obtain_result_by_id("output"),
+        r#"<div><button title="client">Hello, client</button></div>"#
+    );
+}
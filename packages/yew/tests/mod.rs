@@ -4,7 +4,7 @@ mod common;
 
 use std::time::Duration;
 
-use common::obtain_result;
+use common::{obtain_result, obtain_result_by_id};
 use wasm_bindgen_test::*;
 use yew::platform::time::sleep;
 use yew::prelude::*;
@@ -40,3 +40,585 @@ async fn props_are_passed() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "done");
 }
+
+#[wasm_bindgen_test]
+async fn child_components_are_created_and_destroyed() {
+    use std::cell::RefCell;
+
+    thread_local! {
+        static EVENTS: RefCell<Vec<String>> = RefCell::default();
+    }
+
+    fn record(event: String) {
+        EVENTS.with(|events| events.borrow_mut().push(event));
+    }
+
+    #[derive(Properties, Clone, PartialEq)]
+    struct ChildProps {
+        name: AttrValue,
+    }
+
+    struct Child;
+
+    impl Component for Child {
+        type Message = ();
+        type Properties = ChildProps;
+
+        fn create(ctx: &Context<Self>) -> Self {
+            record(format!("create {}", ctx.props().name));
+            Self
+        }
+
+        fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+            record(format!("change {}", ctx.props().name));
+            true
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            html! { <li>{ &ctx.props().name }</li> }
+        }
+
+        fn destroy(&mut self, ctx: &Context<Self>) {
+            record(format!("destroy {}", ctx.props().name));
+        }
+    }
+
+    struct Parent {
+        names: Vec<&'static str>,
+    }
+
+    impl Component for Parent {
+        type Message = Vec<&'static str>;
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                names: vec!["a", "b"],
+            }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, names: Self::Message) -> bool {
+            self.names = names;
+            true
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            html! {
+                <ul id="result-children">
+                    { for self.names.iter().map(|&name| html! { <Child key={name} {name} /> }) }
+                </ul>
+            }
+        }
+    }
+
+    let app = yew::Renderer::<Parent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(
+        obtain_result_by_id("result-children"),
+        "<li>a</li><li>b</li>"
+    );
+    assert_eq!(
+        EVENTS.with(|events| events.take()),
+        vec!["create a", "create b"]
+    );
+
+    // The relative order of creating and destroying siblings is left to the scheduler.
+    let take_sorted = || {
+        let mut events = EVENTS.with(|events| events.take());
+        events.sort();
+        events
+    };
+
+    app.send_message(vec!["b", "c"]);
+    sleep(Duration::ZERO).await;
+    assert_eq!(
+        obtain_result_by_id("result-children"),
+        "<li>b</li><li>c</li>"
+    );
+    assert_eq!(take_sorted(), vec!["create c", "destroy a"]);
+
+    app.destroy();
+    sleep(Duration::ZERO).await;
+    assert_eq!(take_sorted(), vec!["destroy b", "destroy c"]);
+}
+
+#[wasm_bindgen_test]
+async fn child_callbacks_send_messages_to_parent() {
+    use wasm_bindgen::JsCast;
+
+    #[derive(Properties, Clone, PartialEq)]
+    struct ChildProps {
+        on_remove: Callback<()>,
+    }
+
+    #[function_component]
+    fn Child(props: &ChildProps) -> Html {
+        let onclick = props.on_remove.reform(|_: MouseEvent| ());
+        html! { <button id="remove" {onclick}>{"Remove"}</button> }
+    }
+
+    enum Msg {
+        Remove,
+    }
+
+    struct Parent {
+        removed: u32,
+    }
+
+    impl Component for Parent {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self { removed: 0 }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Msg::Remove => self.removed += 1,
+            }
+            true
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let on_remove = ctx.link().callback(|()| Msg::Remove);
+            html! {
+                <>
+                    <Child {on_remove} />
+                    <div id="result-removed">{self.removed}</div>
+                </>
+            }
+        }
+    }
+
+    let app = yew::Renderer::<Parent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result_by_id("result-removed"), "0");
+
+    let button = gloo::utils::document()
+        .get_element_by_id("remove")
+        .unwrap()
+        .dyn_into::<web_sys::HtmlElement>()
+        .unwrap();
+    button.click();
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result_by_id("result-removed"), "1");
+
+    button.click();
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result_by_id("result-removed"), "2");
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn children_with_unchanged_props_are_not_rerendered() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static ROW_RENDERS: Cell<u32> = Cell::new(0);
+    }
+
+    #[derive(Properties, Clone, PartialEq)]
+    struct RowProps {
+        description: AttrValue,
+    }
+
+    #[function_component]
+    fn Row(props: &RowProps) -> Html {
+        ROW_RENDERS.with(|renders| renders.set(renders.get() + 1));
+        html! { <li>{ props.description.clone() }</li> }
+    }
+
+    enum Msg {
+        Tick,
+        Rename,
+    }
+
+    struct List {
+        ticks: u32,
+        description: AttrValue,
+    }
+
+    impl Component for List {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                ticks: 0,
+                description: AttrValue::from("first"),
+            }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Msg::Tick => self.ticks += 1,
+                Msg::Rename => self.description = AttrValue::from("renamed"),
+            }
+            true
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            html! {
+                <>
+                    <div id="result-ticks">{self.ticks}</div>
+                    <ul id="result-rows">
+                        <Row description={self.description.clone()} />
+                    </ul>
+                </>
+            }
+        }
+    }
+
+    let app = yew::Renderer::<List>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(ROW_RENDERS.with(Cell::get), 1);
+
+    app.send_message(Msg::Tick);
+    sleep(Duration::ZERO).await;
+    app.send_message(Msg::Tick);
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result_by_id("result-ticks"), "2");
+    assert_eq!(ROW_RENDERS.with(Cell::get), 1);
+
+    app.send_message(Msg::Rename);
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result_by_id("result-rows"), "<li>renamed</li>");
+    assert_eq!(ROW_RENDERS.with(Cell::get), 2);
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn children_are_rendered_in_order() {
+    #[derive(Properties, PartialEq)]
+    struct CardProps {
+        #[prop_or_default]
+        children: Children,
+    }
+
+    #[function_component]
+    fn Card(props: &CardProps) -> Html {
+        html! {
+            <div class="card">{ for props.children.iter() }</div>
+        }
+    }
+
+    #[function_component]
+    fn Button() -> Html {
+        html! { <button>{"ok"}</button> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <div id="result-children">
+                <Card>
+                    {"content"}
+                    <Button />
+                    <p>{"footer"}</p>
+                </Card>
+                <Card />
+            </div>
+        }
+    }
+
+    let app = yew::Renderer::<App>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!(
+        obtain_result_by_id("result-children"),
+        "<div class=\"card\">content<button>ok</button><p>footer</p></div><div \
+         class=\"card\"></div>"
+    );
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn multiple_apps_are_independent() {
+    use wasm_bindgen::JsCast;
+
+    #[derive(Properties, PartialEq)]
+    struct CounterProps {
+        name: &'static str,
+    }
+
+    #[function_component]
+    fn Counter(props: &CounterProps) -> Html {
+        let count = use_state(|| 0);
+        let onclick = {
+            let count = count.clone();
+            Callback::from(move |_| count.set(*count + 1))
+        };
+
+        html! {
+            <button id={format!("counter-{}", props.name)} {onclick}>{*count}</button>
+        }
+    }
+
+    let output = gloo::utils::document().get_element_by_id("output").unwrap();
+    let mount = |name: &'static str| {
+        let host = gloo::utils::document().create_element("div").unwrap();
+        output.append_child(&host).unwrap();
+        yew::Renderer::<Counter>::with_root_and_props(host, CounterProps { name }).render()
+    };
+    let click = |name: &str| {
+        gloo::utils::document()
+            .get_element_by_id(&format!("counter-{}", name))
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlElement>()
+            .click();
+    };
+
+    let first = mount("first");
+    let second = mount("second");
+    sleep(Duration::ZERO).await;
+
+    click("first");
+    click("first");
+    click("second");
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result_by_id("counter-first"), "2");
+    assert_eq!(obtain_result_by_id("counter-second"), "1");
+
+    // Destroying one app leaves the other running
+    first.destroy();
+    sleep(Duration::ZERO).await;
+    click("second");
+    sleep(Duration::ZERO).await;
+    assert!(gloo::utils::document()
+        .get_element_by_id("counter-first")
+        .is_none());
+    assert_eq!(obtain_result_by_id("counter-second"), "2");
+
+    second.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn destroyed_app_releases_listeners_and_state() {
+    use std::cell::Cell;
+
+    use wasm_bindgen::JsCast;
+
+    thread_local! {
+        static CLICKS: Cell<u32> = Cell::new(0);
+        static DROPPED: Cell<bool> = Cell::new(false);
+    }
+
+    // Stands in for a timer or socket owned by the component
+    struct Task;
+
+    impl Drop for Task {
+        fn drop(&mut self) {
+            DROPPED.with(|dropped| dropped.set(true));
+        }
+    }
+
+    struct Comp {
+        _task: Task,
+    }
+
+    impl Component for Comp {
+        type Message = ();
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self { _task: Task }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+            CLICKS.with(|clicks| clicks.set(clicks.get() + 1));
+            false
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            html! { <button id="destroy-me" onclick={ctx.link().callback(|_| ())}>{"Click"}</button> }
+        }
+    }
+
+    let host = gloo::utils::document().get_element_by_id("output").unwrap();
+    let app = yew::Renderer::<Comp>::with_root(host.clone()).render();
+    sleep(Duration::ZERO).await;
+
+    let button = gloo::utils::document()
+        .get_element_by_id("destroy-me")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>();
+    button.click();
+    sleep(Duration::ZERO).await;
+    assert_eq!(CLICKS.with(Cell::get), 1);
+
+    app.destroy();
+    sleep(Duration::ZERO).await;
+    assert!(DROPPED.with(Cell::get));
+    assert_eq!(host.inner_html(), "");
+
+    // Even an event on a retained node doesn't reach the destroyed component anymore
+    host.append_child(&button).unwrap();
+    button.click();
+    sleep(Duration::ZERO).await;
+    assert_eq!(CLICKS.with(Cell::get), 1);
+    button.remove();
+}
+
+#[wasm_bindgen_test]
+async fn mapped_child_messages_reach_parent() {
+    use wasm_bindgen::JsCast;
+
+    enum CounterMsg {
+        Increment,
+    }
+
+    enum Msg {
+        Counter(CounterMsg),
+    }
+
+    // A view fragment that only knows about its own message type
+    fn counter_view(id: &'static str, on_msg: Callback<CounterMsg>) -> Html {
+        html! {
+            <button {id} onclick={on_msg.reform(|_| CounterMsg::Increment)}>{"+"}</button>
+        }
+    }
+
+    struct Parent {
+        log: Vec<&'static str>,
+    }
+
+    impl Component for Parent {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self { log: Vec::new() }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Msg::Counter(CounterMsg::Increment) => self.log.push("increment"),
+            }
+            true
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            html! {
+                <>
+                    { counter_view("mapped", ctx.link().callback(Msg::Counter)) }
+                    <div id="result">{ self.log.join(",") }</div>
+                </>
+            }
+        }
+    }
+
+    let app = yew::Renderer::<Parent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    gloo::utils::document()
+        .get_element_by_id("mapped")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "increment");
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn reused_html_skips_props_comparison() {
+    use std::cell::Cell;
+
+    use wasm_bindgen::JsCast;
+
+    thread_local! {
+        static COMPARISONS: Cell<u32> = Cell::new(0);
+        static FOOTER_VIEWS: Cell<u32> = Cell::new(0);
+    }
+
+    #[derive(Properties)]
+    struct FooterProps {
+        text: AttrValue,
+    }
+
+    impl PartialEq for FooterProps {
+        fn eq(&self, other: &Self) -> bool {
+            COMPARISONS.with(|comparisons| comparisons.set(comparisons.get() + 1));
+            self.text == other.text
+        }
+    }
+
+    #[function_component]
+    fn Footer(props: &FooterProps) -> Html {
+        FOOTER_VIEWS.with(|views| views.set(views.get() + 1));
+        html! { <footer>{ &props.text }</footer> }
+    }
+
+    struct Page {
+        clicks: u32,
+        footer: Html,
+    }
+
+    impl Component for Page {
+        type Message = ();
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                clicks: 0,
+                footer: html! { <Footer text="static" /> },
+            }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+            self.clicks += 1;
+            true
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            html! {
+                <>
+                    <button id="rerender" onclick={ctx.link().callback(|_| ())}>{"+"}</button>
+                    <div id="result">{ self.clicks }</div>
+                    { self.footer.clone() }
+                </>
+            }
+        }
+    }
+
+    let app = yew::Renderer::<Page>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    let button = gloo::utils::document()
+        .get_element_by_id("rerender")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>();
+    button.click();
+    button.click();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "2");
+    assert_eq!(FOOTER_VIEWS.with(Cell::get), 1);
+    // The props are shared with the previous render, so they didn't need to be compared
+    assert_eq!(COMPARISONS.with(Cell::get), 0);
+
+    app.destroy();
+}
@@ -0,0 +1,369 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{obtain_result, obtain_result_by_id, output_element};
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn futures_sent_from_update_deliver_messages() {
+    enum Msg {
+        Fetch,
+        Fetched(u32),
+    }
+
+    struct Comp {
+        value: Option<u32>,
+    }
+
+    impl Component for Comp {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(ctx: &Context<Self>) -> Self {
+            ctx.link().send_message(Msg::Fetch);
+            Self { value: None }
+        }
+
+        fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Msg::Fetch => {
+                    ctx.link().send_future(async {
+                        sleep(Duration::from_millis(10)).await;
+                        Msg::Fetched(42)
+                    });
+                    false
+                }
+                Msg::Fetched(value) => {
+                    self.value = Some(value);
+                    true
+                }
+            }
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            match self.value {
+                Some(value) => html! { <div id="result">{value}</div> },
+                None => html! { <div id="result">{"pending"}</div> },
+            }
+        }
+    }
+
+    let app = yew::Renderer::<Comp>::with_root(output_element()).render();
+
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result(), "pending");
+
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(obtain_result(), "42");
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn messages_of_futures_resolving_after_destroy_are_discarded() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static LOADED: Cell<Option<u32>> = Cell::new(None);
+        static LOAD_FINISHED: Cell<bool> = Cell::new(false);
+    }
+
+    async fn load() -> Result<u32, ()> {
+        sleep(Duration::from_millis(10)).await;
+        LOAD_FINISHED.with(|finished| finished.set(true));
+        Ok(42)
+    }
+
+    enum Msg {
+        Loaded(Result<u32, ()>),
+    }
+
+    struct Comp;
+
+    impl Component for Comp {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(ctx: &Context<Self>) -> Self {
+            ctx.link().send_future(async { Msg::Loaded(load().await) });
+            Self
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            let Msg::Loaded(result) = msg;
+            LOADED.with(|loaded| loaded.set(result.ok()));
+            true
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            html! { <div id="result">{"pending"}</div> }
+        }
+    }
+
+    let app = yew::Renderer::<Comp>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+    app.destroy();
+
+    sleep(Duration::from_millis(50)).await;
+    assert!(LOAD_FINISHED.with(Cell::get));
+    assert_eq!(LOADED.with(Cell::get), None);
+}
+
+#[wasm_bindgen_test]
+async fn futures_until_destroyed_are_dropped_on_destroy() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static LOADED: Cell<Option<u32>> = Cell::new(None);
+        static LOAD_FINISHED: Cell<bool> = Cell::new(false);
+        static LOAD_DROPPED: Cell<bool> = Cell::new(false);
+    }
+
+    struct DropGuard;
+
+    impl Drop for DropGuard {
+        fn drop(&mut self) {
+            LOAD_DROPPED.with(|dropped| dropped.set(true));
+        }
+    }
+
+    async fn load() -> Result<u32, ()> {
+        let _guard = DropGuard;
+        sleep(Duration::from_millis(10)).await;
+        LOAD_FINISHED.with(|finished| finished.set(true));
+        Ok(42)
+    }
+
+    enum Msg {
+        Loaded(Result<u32, ()>),
+    }
+
+    struct Comp;
+
+    impl Component for Comp {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(ctx: &Context<Self>) -> Self {
+            ctx.link()
+                .send_future_until_destroyed(async { Msg::Loaded(load().await) });
+            Self
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            let Msg::Loaded(result) = msg;
+            LOADED.with(|loaded| loaded.set(result.ok()));
+            true
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            html! { <div id="result">{"pending"}</div> }
+        }
+    }
+
+    let app = yew::Renderer::<Comp>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+    assert!(!LOAD_DROPPED.with(Cell::get));
+    app.destroy();
+
+    sleep(Duration::from_millis(50)).await;
+    assert!(LOAD_DROPPED.with(Cell::get));
+    assert!(!LOAD_FINISHED.with(Cell::get));
+    assert_eq!(LOADED.with(Cell::get), None);
+}
+
+#[wasm_bindgen_test]
+async fn update_returning_false_skips_view() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static VIEW_CALLS: Cell<u32> = Cell::new(0);
+    }
+
+    enum Msg {
+        Noop,
+        Increment,
+    }
+
+    struct Comp {
+        count: u32,
+    }
+
+    impl Component for Comp {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self { count: 0 }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Msg::Noop => false,
+                Msg::Increment => {
+                    self.count += 1;
+                    true
+                }
+            }
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            VIEW_CALLS.with(|calls| calls.set(calls.get() + 1));
+            html! { <div id="result">{self.count}</div> }
+        }
+    }
+
+    let app = yew::Renderer::<Comp>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(VIEW_CALLS.with(Cell::get), 1);
+
+    for _ in 0..3 {
+        app.send_message(Msg::Noop);
+        sleep(Duration::ZERO).await;
+    }
+    assert_eq!(VIEW_CALLS.with(Cell::get), 1);
+    assert_eq!(obtain_result(), "0");
+
+    app.send_message(Msg::Increment);
+    sleep(Duration::ZERO).await;
+    assert_eq!(VIEW_CALLS.with(Cell::get), 2);
+    assert_eq!(obtain_result(), "1");
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn messages_sent_in_one_tick_are_rendered_once() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static VIEW_CALLS: Cell<u32> = Cell::new(0);
+    }
+
+    enum Msg {
+        Push(u32),
+        PushTwice(u32),
+    }
+
+    struct Comp {
+        pushed: Vec<u32>,
+    }
+
+    impl Component for Comp {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self { pushed: Vec::new() }
+        }
+
+        fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Msg::Push(value) => self.pushed.push(value),
+                Msg::PushTwice(value) => {
+                    self.pushed.push(value);
+                    ctx.link().send_message(Msg::Push(value));
+                }
+            }
+            true
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            VIEW_CALLS.with(|calls| calls.set(calls.get() + 1));
+            let pushed = self
+                .pushed
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            html! { <div id="result-batched">{pushed}</div> }
+        }
+    }
+
+    let app = yew::Renderer::<Comp>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(VIEW_CALLS.with(Cell::get), 1);
+
+    app.send_message(Msg::Push(1));
+    app.send_message(Msg::PushTwice(2));
+    app.send_message_batch(vec![Msg::Push(3), Msg::Push(4)]);
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(VIEW_CALLS.with(Cell::get), 2);
+    assert_eq!(obtain_result_by_id("result-batched"), "1,2,3,4,2");
+
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn first_render_runs_once_after_nodes_are_mounted() {
+    enum Msg {
+        Mounted,
+        Clicked,
+    }
+
+    struct Comp {
+        input_ref: NodeRef,
+        log: Vec<&'static str>,
+    }
+
+    impl Component for Comp {
+        type Message = Msg;
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self {
+                input_ref: NodeRef::default(),
+                log: Vec::new(),
+            }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Msg::Mounted => self.log.push("mounted"),
+                Msg::Clicked => self.log.push("clicked"),
+            }
+            true
+        }
+
+        fn view(&self, _ctx: &Context<Self>) -> Html {
+            html! {
+                <>
+                    <input ref={&self.input_ref} />
+                    <div id="result-mounted">{self.log.join(",")}</div>
+                </>
+            }
+        }
+
+        fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+            if first_render {
+                let input = self.input_ref.cast::<web_sys::HtmlElement>().unwrap();
+                assert!(input.is_connected());
+                ctx.link().send_message(Msg::Mounted);
+            }
+        }
+    }
+
+    let app = yew::Renderer::<Comp>::with_root(output_element()).render();
+    sleep(Duration::ZERO).await;
+    assert_eq!(obtain_result_by_id("result-mounted"), "mounted");
+
+    // Re-rendering after an update must not count as mounting again
+    app.send_message(Msg::Clicked);
+    sleep(Duration::ZERO).await;
+    app.send_message(Msg::Clicked);
+    sleep(Duration::ZERO).await;
+    assert_eq!(
+        obtain_result_by_id("result-mounted"),
+        "mounted,clicked,clicked"
+    );
+
+    app.destroy();
+}
@@ -70,3 +70,73 @@ async fn use_callback_works() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "Hello, Yew!");
 }
+
+#[wasm_bindgen_test]
+async fn memoized_callback_skips_child_render() {
+    use std::cell::Cell;
+
+    use wasm_bindgen::JsCast;
+
+    thread_local! {
+        static MEMOIZED_RENDERS: Cell<u32> = Cell::new(0);
+        static RECREATED_RENDERS: Cell<u32> = Cell::new(0);
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct ChildProps {
+        onclick: Callback<MouseEvent>,
+        memoized: bool,
+    }
+
+    #[function_component]
+    fn Child(props: &ChildProps) -> Html {
+        let renders = if props.memoized {
+            &MEMOIZED_RENDERS
+        } else {
+            &RECREATED_RENDERS
+        };
+        renders.with(|renders| renders.set(renders.get() + 1));
+        html! { <button onclick={&props.onclick}>{"Child"}</button> }
+    }
+
+    #[function_component]
+    fn Parent() -> Html {
+        let count = use_state(|| 0);
+
+        let memoized = use_callback(|_, _| (), ());
+        let recreated = Callback::from(|_| ());
+        let increment = {
+            let count = count.clone();
+            Callback::from(move |_| count.set(*count + 1))
+        };
+
+        html! {
+            <>
+                <Child onclick={memoized} memoized=true />
+                <Child onclick={recreated} memoized=false />
+                // An unrelated sibling that changes on every click
+                <button id="increment" onclick={increment}>{"+"}</button>
+                <div id="result">{*count}</div>
+            </>
+        }
+    }
+
+    yew::Renderer::<Parent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    sleep(Duration::ZERO).await;
+
+    let button = gloo::utils::document()
+        .get_element_by_id("increment")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>();
+    button.click();
+    sleep(Duration::ZERO).await;
+    button.click();
+    sleep(Duration::ZERO).await;
+
+    assert_eq!(obtain_result(), "2");
+    assert_eq!(MEMOIZED_RENDERS.with(Cell::get), 1);
+    assert_eq!(RECREATED_RENDERS.with(Cell::get), 3);
+}
@@ -0,0 +1,123 @@
+//! Measures how long reconciling a large static subtree takes when the subtree is rebuilt on
+//! every render, compared to building it once and reusing the same `Html`.
+//!
+//! Run it in a browser with `wasm-pack test --release --headless --firefox` from this directory.
+#![cfg(target_arch = "wasm32")]
+
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+use wasm_bindgen_test::*;
+use yew::html::Scope;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ROWS: usize = 1000;
+const RENDERS: u32 = 100;
+
+thread_local! {
+    static LINK: RefCell<Option<Scope<Page>>> = RefCell::default();
+    static RECONCILE_STARTED: Cell<f64> = Cell::default();
+    static RECONCILE_TIME: Cell<f64> = Cell::default();
+}
+
+fn now() -> f64 {
+    gloo::utils::window().performance().unwrap().now()
+}
+
+fn footer() -> Html {
+    html! {
+        <ul>
+            { for (0..ROWS).map(|row| html! { <li class="row"><span>{"Row "}{row}</span></li> }) }
+        </ul>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct PageProps {
+    reuse: bool,
+}
+
+struct Page {
+    renders: u32,
+    footer: Html,
+}
+
+impl Component for Page {
+    type Message = ();
+    type Properties = PageProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        LINK.with(|link| *link.borrow_mut() = Some(ctx.link().clone()));
+        Self {
+            renders: 0,
+            footer: footer(),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+        self.renders += 1;
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let footer = if ctx.props().reuse {
+            self.footer.clone()
+        } else {
+            footer()
+        };
+        let html = html! {
+            <>
+                <p>{ self.renders }</p>
+                { footer }
+            </>
+        };
+        // Building the `Html` is not part of the measurement, only reconciling it
+        RECONCILE_STARTED.with(|started| started.set(now()));
+        html
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if !first_render {
+            let elapsed = now() - RECONCILE_STARTED.with(Cell::get);
+            RECONCILE_TIME.with(|time| time.set(time.get() + elapsed));
+        }
+    }
+}
+
+/// Renders the page [`RENDERS`] times and returns the total time spent reconciling, in ms.
+async fn measure(reuse: bool) -> f64 {
+    let root = gloo::utils::document().create_element("div").unwrap();
+    gloo::utils::body().append_child(&root).unwrap();
+    let app =
+        yew::Renderer::<Page>::with_root_and_props(root.clone(), PageProps { reuse }).render();
+    sleep(Duration::ZERO).await;
+
+    RECONCILE_TIME.with(|time| time.set(0.0));
+    let link = LINK.with(|link| link.borrow_mut().take().unwrap());
+    for _ in 0..RENDERS {
+        link.send_message(());
+        sleep(Duration::ZERO).await;
+    }
+    assert_eq!(root.query_selector_all("li").unwrap().length(), ROWS as u32);
+
+    app.destroy();
+    root.remove();
+    RECONCILE_TIME.with(Cell::get)
+}
+
+#[wasm_bindgen_test]
+async fn reused_static_subtree() {
+    let rebuilt = measure(false).await;
+    let reused = measure(true).await;
+
+    console_log!(
+        "reconciling {} rows {} times: {:.1}ms when rebuilt, {:.1}ms when reused",
+        ROWS,
+        RENDERS,
+        rebuilt,
+        reused
+    );
+}
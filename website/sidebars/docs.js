@@ -140,6 +140,7 @@ module.exports = {
             },
             items: [
                 'more/debugging',
+                'more/browser-apis',
                 'more/deployment',
                 'more/css',
                 'more/testing',